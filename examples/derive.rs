@@ -0,0 +1,64 @@
+use gtk::glib;
+use gtk::prelude::*;
+
+glib::wrapper! {
+    pub struct MyClass(ObjectSubclass<imp::MyClass>);
+}
+
+impl MyClass {
+    fn new() -> Self {
+        glib::Object::new(&[("name", &"Initial Name")]).unwrap()
+    }
+}
+
+mod imp {
+    use gtk::glib;
+    use gtk::subclass::prelude::*;
+    use gtk_properties_macro::{derived_properties, Properties};
+    use std::cell::{Cell, RefCell};
+
+    // Each field marked with `#[property(...)]` becomes a property. The arguments are
+    // the same as for a declaration within `properties!`, starting with the type tag.
+    //
+    // The property name is derived from the field name ("click-count" here),
+    // unless it is given explicitly with `name = "..."`.
+    #[derive(Default, Properties)]
+    pub struct MyClass {
+        /// Name of this object
+        #[property(string, construct, nick = "Object Name")]
+        name: RefCell<String>,
+        #[property(int, readable)]
+        click_count: Cell<i32>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for MyClass {
+        const NAME: &'static str = "MyClass";
+        type Type = super::MyClass;
+        type ParentType = gtk::glib::Object;
+    }
+
+    impl ObjectImpl for MyClass {
+        // Implements `properties`, `property` and `set_property`, by delegating to the
+        // `derived_properties`, `derived_property` and `derived_set_property` methods
+        // generated by `#[derive(Properties)]`.
+        derived_properties!();
+    }
+}
+
+fn main() {
+    let my_object = MyClass::new();
+    println!(
+        "Object name before set: {:?}",
+        my_object.property::<String>("name")
+    );
+    my_object.set_property("name", "New Name".to_string().to_value());
+    println!(
+        "Object name after set: {:?}",
+        my_object.property::<String>("name")
+    );
+    println!(
+        "Click count: {:?}",
+        my_object.property::<i32>("click-count")
+    );
+}
@@ -0,0 +1,255 @@
+use crate::parse::{Body, Head, Properties, Property};
+use proc_macro2::TokenStream as TS;
+use quote::{quote, quote_spanned, ToTokens};
+use std::collections::{HashMap, HashSet};
+use syn::{spanned::Spanned, LitStr, Meta, NestedMeta};
+
+// Every name listed in `notifies = [...]` must refer to a property declared
+// within the same `properties!` block.
+pub fn check_notifies(properties: &Properties) {
+    let names = property_names(properties);
+    for property in &properties.properties.0 {
+        for notified in property.head.declaration.notifies() {
+            if !names.contains(&notified.value()) {
+                notified
+                    .span()
+                    .unwrap()
+                    .error(format!(
+                        "Property {:?} notifies unknown property {:?}",
+                        property.name.value(),
+                        notified.value()
+                    ))
+                    .help("Only properties declared within the same `properties!` block can be notified")
+                    .emit();
+            }
+        }
+    }
+}
+
+// Property names must be unique, also across included property groups and aliases.
+// Declarations of the same name are accepted if their `#[cfg(...)]` conditions are mutually exclusive,
+// e.g. `unix` and `not(unix)`. Other conditional duplicates can't be checked when the macro is expanded,
+// so the returned `compile_error!`s report them if both conditions are met.
+pub fn check_unique_names(properties: &Properties) -> TS {
+    let mut seen: HashMap<String, Vec<(&LitStr, &Property)>> = HashMap::new();
+    let mut conditional_errors = vec![];
+    for property in &properties.properties.0 {
+        let names =
+            std::iter::once(&property.name).chain(property.aliases.iter().map(|alias| &alias.name));
+        for name in names {
+            let previous_declarations = seen.entry(name.value()).or_default();
+            for (previous_name, previous) in previous_declarations.iter() {
+                match (previous.head.condition(), property.head.condition()) {
+                    _ if exclusive(&previous.head, &property.head) => {}
+                    (Some(previous_condition), Some(condition)) => {
+                        let message = format!(
+                            "Duplicate property {:?}, which is declared twice if both `{}` and `{}` hold",
+                            name.value(),
+                            previous_condition,
+                            condition
+                        );
+                        conditional_errors.push(quote_spanned! { name.span() =>
+                            #[cfg(all(#previous_condition, #condition))]
+                            compile_error!(#message);
+                        });
+                    }
+                    _ => {
+                        name.span()
+                            .unwrap()
+                            .error(format!("Duplicate property {:?}", name.value()))
+                            .span_note(previous_name.span().unwrap(), "previously declared here")
+                            .emit();
+                        break;
+                    }
+                }
+            }
+            previous_declarations.push((name, property));
+        }
+    }
+    quote! { #(#conditional_errors)* }
+}
+
+// Whether two declarations are never compiled together,
+// i.e. one of them requires `not(X)`, and the other one requires `X`.
+fn exclusive(a: &Head, b: &Head) -> bool {
+    negates(&a.cfg, &b.cfg) || negates(&b.cfg, &a.cfg)
+}
+
+fn negates(a: &[NestedMeta], b: &[NestedMeta]) -> bool {
+    a.iter().any(|condition| match condition {
+        NestedMeta::Meta(Meta::List(list))
+            if list.path.is_ident("not") && list.nested.len() == 1 =>
+        {
+            let negated = list.nested[0].to_token_stream().to_string();
+            b.iter()
+                .any(|condition| condition.to_token_stream().to_string() == negated)
+        }
+        _ => false,
+    })
+}
+
+// Maps the name of each property (and alias) to the `#[cfg(...)]` attribute under which it is declared,
+// so that notifying it can be compiled out together with the property.
+// The attribute is empty when any of its declarations is unconditional.
+pub fn conditions(properties: &Properties) -> HashMap<String, TS> {
+    let mut conditions: HashMap<String, Option<Vec<TS>>> = HashMap::new();
+    for property in &properties.properties.0 {
+        let condition = property.head.condition();
+        let names = std::iter::once(property.name.value())
+            .chain(property.aliases.iter().map(|alias| alias.name.value()));
+        for name in names {
+            let entry = conditions.entry(name).or_insert_with(|| Some(vec![]));
+            match (entry.as_mut(), &condition) {
+                (Some(alternatives), Some(condition)) => alternatives.push(condition.clone()),
+                _ => *entry = None,
+            }
+        }
+    }
+    conditions
+        .into_iter()
+        .map(|(name, alternatives)| {
+            let cfg = match alternatives.as_deref() {
+                None | Some([]) => quote! {},
+                Some([condition]) => quote! { #[cfg(#condition)] },
+                Some(alternatives) => quote! { #[cfg(any(#(#alternatives),*))] },
+            };
+            (name, cfg)
+        })
+        .collect()
+}
+
+// Maps the name of each property to the computed properties that depend on it,
+// directly or through other computed properties.
+//
+// Unknown dependencies and cycles between computed properties are reported
+// as errors, and left out of the result.
+pub fn dependents(properties: &Properties) -> HashMap<String, Vec<String>> {
+    let names = property_names(properties);
+    let computed: Vec<(String, Vec<String>)> = properties
+        .properties
+         .0
+        .iter()
+        .filter_map(|property| match &property.body {
+            Body::Computed(computed) => Some((property, computed)),
+            _ => None,
+        })
+        .map(|(property, computed)| {
+            let dependencies = computed
+                .dependency_names()
+                .into_iter()
+                .filter(|(name, ident)| {
+                    if !names.contains(name) {
+                        ident
+                            .span()
+                            .unwrap()
+                            .error(format!(
+                                "Computed property {:?} depends on unknown property {:?}",
+                                property.name.value(),
+                                name
+                            ))
+                            .help("Only properties declared within the same `properties!` block can be dependencies")
+                            .emit();
+                        false
+                    } else if let Some(path) = find_cycle(properties, property, name) {
+                        ident
+                            .span()
+                            .unwrap()
+                            .error(format!(
+                                "Computed property {:?} depends on itself: {}",
+                                property.name.value(),
+                                path.join(" -> ")
+                            ))
+                            .emit();
+                        false
+                    } else {
+                        true
+                    }
+                })
+                .map(|(name, _)| name)
+                .collect();
+            (property.name.value(), dependencies)
+        })
+        .collect();
+
+    let mut dependents = HashMap::new();
+    for name in names {
+        let mut result = vec![];
+        collect_dependents(&computed, &name, &mut result);
+        if !result.is_empty() {
+            dependents.insert(name, result);
+        }
+    }
+    dependents
+}
+
+fn collect_dependents(computed: &[(String, Vec<String>)], name: &str, result: &mut Vec<String>) {
+    for (dependent, dependencies) in computed {
+        if dependencies.iter().any(|dependency| dependency == name) && !result.contains(dependent) {
+            result.push(dependent.clone());
+            collect_dependents(computed, dependent, result);
+        }
+    }
+}
+
+// Follows the dependencies of computed properties, starting at `dependency`.
+// Returns the path back to `property`, if there is one.
+fn find_cycle(
+    properties: &Properties,
+    property: &Property,
+    dependency: &str,
+) -> Option<Vec<String>> {
+    let mut visited = HashSet::new();
+    let mut path = vec![property.name.value()];
+    if visit(
+        properties,
+        &property.name.value(),
+        dependency,
+        &mut visited,
+        &mut path,
+    ) {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+fn visit(
+    properties: &Properties,
+    target: &str,
+    current: &str,
+    visited: &mut HashSet<String>,
+    path: &mut Vec<String>,
+) -> bool {
+    path.push(current.to_string());
+    if current == target {
+        return true;
+    }
+    if visited.insert(current.to_string()) {
+        let computed = properties
+            .properties
+            .0
+            .iter()
+            .find_map(|property| match &property.body {
+                Body::Computed(computed) if property.name.value() == current => Some(computed),
+                _ => None,
+            });
+        if let Some(computed) = computed {
+            for (name, _) in computed.dependency_names() {
+                if visit(properties, target, &name, visited, path) {
+                    return true;
+                }
+            }
+        }
+    }
+    path.pop();
+    false
+}
+
+fn property_names(properties: &Properties) -> HashSet<String> {
+    properties
+        .properties
+        .0
+        .iter()
+        .map(|property| property.name.value())
+        .collect()
+}
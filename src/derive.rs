@@ -0,0 +1,290 @@
+use crate::generate::is_type_tag;
+use crate::parse::{
+    join_path, parse_doc, property_name, Alias, Body, Declaration, DeclarationArg, DeclarationArgs,
+    Head, LooselySeparated, Options, Properties, Property, Storage, StorageKind,
+};
+use quote::ToTokens;
+use syn::{
+    parse::ParseStream, punctuated::Punctuated, spanned::Spanned, token, Data, DeriveInput, Field,
+    Fields, GenericArgument, Ident, LitStr, Path, PathArguments, Result, Type,
+};
+
+// Translates the fields of a struct, which are marked with `#[property(...)]`,
+// into the same property declarations that `properties!` works with.
+//
+// `#[property(int, construct)] count: Cell<i32>` is equivalent to
+// `#[int(construct)] "count" => cell(count)`. The type tag may also be
+// omitted (`#[property(construct)]`), if it can be inferred from the field type.
+pub fn properties(input: &DeriveInput) -> Result<Properties> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "Properties can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "Properties can only be derived for structs",
+            ))
+        }
+    };
+
+    // options for the entire struct, e.g. `#[properties(default_from_struct)]`
+    let mut options = Options::default();
+    for attr in &input.attrs {
+        if join_path(&attr.path) == "properties" {
+            attr.parse_args_with(|input: ParseStream| options.parse_list(input))?;
+        }
+    }
+    if options.interface {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "Properties of interfaces cannot be derived from struct fields",
+        ));
+    }
+
+    let mut properties = vec![];
+    for field in fields {
+        if let Some(property) = field_property(field)? {
+            properties.push(property);
+        }
+    }
+
+    Ok(Properties {
+        options,
+        properties: LooselySeparated(properties),
+        constructed: None,
+    })
+}
+
+fn field_property(field: &Field) -> Result<Option<Property>> {
+    let ident = field.ident.as_ref().unwrap();
+    let mut doc = vec![];
+    let mut args = None;
+    for attr in &field.attrs {
+        match join_path(&attr.path).as_str() {
+            "doc" => doc.push(parse_doc(attr.clone())?),
+            "property" => {
+                if args.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        &attr.path,
+                        "Duplicate #[property] attribute",
+                    ));
+                }
+                args = Some(if attr.tokens.is_empty() {
+                    DeclarationArgs {
+                        paren: token::Paren::default(),
+                        args: Punctuated::new(),
+                    }
+                } else {
+                    syn::parse2::<DeclarationArgs>(attr.tokens.clone())?
+                });
+            }
+            _ => {}
+        }
+    }
+    let mut args = match args {
+        Some(args) => args,
+        None => return Ok(None),
+    };
+
+    // the type tag can be omitted, if it can be inferred from the field type
+    let tag = match args.args.first() {
+        Some(DeclarationArg::Tag(tag)) if is_type_tag(&join_path(tag)) => {
+            let tag = join_path(tag);
+            args.args = args.args.into_iter().skip(1).collect();
+            tag
+        }
+        _ => {
+            let (tag, type_arg) = infer_type_tag(&field.ty)?;
+            if let Some(type_arg) = type_arg {
+                args.args.insert(0, DeclarationArg::Tag(type_arg));
+            }
+            tag.to_string()
+        }
+    };
+
+    // strings stored as `Option<String>` accept `None`
+    let is_nullable = |arg: &DeclarationArg| matches!(arg, DeclarationArg::Tag(tag) if join_path(tag) == "nullable");
+    if tag == "string" && is_optional_string(&field.ty) && !args.args.iter().any(is_nullable) {
+        args.args.push(DeclarationArg::Tag(Path::from(Ident::new(
+            "nullable",
+            ident.span(),
+        ))));
+    }
+
+    // `name = "..."` overrides the name derived from the field,
+    // `alias = "..."` declares a deprecated alias
+    let mut name = LitStr::new(&property_name(ident), ident.span());
+    let mut aliases = vec![];
+    args.args = args
+        .args
+        .into_iter()
+        .filter(|arg| match arg {
+            DeclarationArg::KeyVal(key, _, syn::Lit::Str(value)) if join_path(key) == "name" => {
+                name = value.clone();
+                false
+            }
+            DeclarationArg::KeyVal(key, _, syn::Lit::Str(value)) if join_path(key) == "alias" => {
+                aliases.push(Alias {
+                    ident: key.get_ident().unwrap().clone(),
+                    name: value.clone(),
+                    deprecated: true,
+                });
+                false
+            }
+            _ => true,
+        })
+        .collect();
+
+    Ok(Some(Property {
+        head: Head {
+            doc,
+            declaration: Declaration {
+                tag,
+                args: if args.args.is_empty() {
+                    None
+                } else {
+                    Some(args)
+                },
+            },
+            // fields are configured out before the derive is expanded
+            cfg: vec![],
+        },
+        name,
+        aliases,
+        arrow: None,
+        body: Body::Storage(storage(&field.ty, ident)?),
+    }))
+}
+
+// Type tag for a field type, e.g. "int" for `Cell<i32>`.
+// For "object" and "enum", the type argument is returned as well.
+fn infer_type_tag(ty: &Type) -> Result<(&'static str, Option<Path>)> {
+    let inferred = match (outer_type(ty), type_argument(ty)) {
+        (Some("Cell"), Some(inner)) => match path_of(inner).map(join_path).as_deref() {
+            // other primitive types don't have a corresponding type tag (yet)
+            Some(
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i16" | "i128" | "isize" | "char",
+            ) => None,
+            Some(name) => {
+                Some(primitive_type_tag(name).unwrap_or(("enum", path_of(inner).cloned())))
+            }
+            None => None,
+        },
+        (Some("OnceCell"), Some(inner)) if outer_type(inner) == Some("String") => {
+            Some(("string", None))
+        }
+        // an enum can't be told apart from an object here
+        (Some("OnceCell"), Some(inner)) => {
+            let inferred = path_of(inner)
+                .map(join_path)
+                .and_then(|name| primitive_type_tag(&name));
+            if inferred.is_none() {
+                let inner = inner.to_token_stream().to_string().replace(' ', "");
+                return Err(syn::Error::new(
+                    ty.span(),
+                    format!(
+                        "Cannot infer the property type from `{}`, a type tag is required for `OnceCell` fields of enums and objects, e.g. #[property(enum, {inner})] or #[property(object, {inner})]",
+                        ty.to_token_stream().to_string().replace(' ', "")
+                    ),
+                ));
+            }
+            inferred
+        }
+        (Some("RefCell"), Some(inner)) => match (outer_type(inner), type_argument(inner)) {
+            (Some("String"), None) => Some(("string", None)),
+            (Some("Option"), Some(object)) if outer_type(object) == Some("String") => {
+                Some(("string", None))
+            }
+            (Some("Option"), Some(object)) => Some(("object", path_of(object).cloned())),
+            _ => None,
+        },
+        (Some("WeakRef"), Some(object)) => Some(("object", path_of(object).cloned())),
+        _ => None,
+    };
+    inferred.ok_or_else(|| {
+        syn::Error::new(
+            ty.span(),
+            format!(
+                "Cannot infer the property type from `{}`, please specify a type tag, e.g. #[property(int)] or #[property(object, gtk::Widget)]",
+                ty.to_token_stream().to_string().replace(' ', "")
+            ),
+        )
+    })
+}
+
+fn primitive_type_tag(name: &str) -> Option<(&'static str, Option<Path>)> {
+    let tag = match name {
+        "bool" => "boolean",
+        "i8" => "char",
+        "i32" => "int",
+        "i64" => "int64",
+        "f32" => "float",
+        "f64" => "double",
+        _ => return None,
+    };
+    Some((tag, None))
+}
+
+// `RefCell<Option<String>>`
+fn is_optional_string(ty: &Type) -> bool {
+    match type_argument(ty) {
+        Some(inner) if outer_type(ty) == Some("RefCell") && outer_type(inner) == Some("Option") => {
+            type_argument(inner).and_then(outer_type) == Some("String")
+        }
+        _ => false,
+    }
+}
+
+fn path_of(ty: &Type) -> Option<&Path> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => Some(&path.path),
+        _ => None,
+    }
+}
+
+// name of the outermost type, e.g. "RefCell" for `std::cell::RefCell<String>`
+fn outer_type(ty: &Type) -> Option<&'static str> {
+    let name = path_of(ty)?.segments.last()?.ident.to_string();
+    ["Cell", "RefCell", "WeakRef", "OnceCell", "Option", "String"]
+        .into_iter()
+        .find(|known| *known == name)
+}
+
+// the `T` in `Cell<T>`, `Option<T>`, ...
+fn type_argument(ty: &Type) -> Option<&Type> {
+    match &path_of(ty)?.segments.last()?.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+// `cell(field)` for `Cell<T>`, `refcell(field)` for `RefCell<T>`, `weak(field)` for `WeakRef<T>`
+// and `once(field)` for `OnceCell<T>`
+fn storage(ty: &Type, field: &Ident) -> Result<Storage> {
+    let (name, kind) = match outer_type(ty) {
+        Some("Cell") => ("cell", StorageKind::Cell),
+        Some("RefCell") => ("refcell", StorageKind::RefCell),
+        Some("WeakRef") => ("weak", StorageKind::Weak),
+        Some("OnceCell") => ("once", StorageKind::Once),
+        _ => return Err(syn::Error::new(
+            ty.span(),
+            "Unsupported field type for #[property], expected Cell<T>, RefCell<T>, WeakRef<T> or OnceCell<T>",
+        )),
+    };
+    Ok(Storage {
+        ident: Ident::new(name, ty.span()),
+        kind,
+        _paren: token::Paren::default(),
+        field: field.clone(),
+    })
+}
@@ -0,0 +1,88 @@
+// Turns the lines of doc comments into plain text, for the blurb (and nick) of a ParamSpec.
+//
+// Each line is trimmed, and the lines of a paragraph are joined with spaces.
+// Markdown which doesn't read well as plain text is removed:
+// code blocks, link definitions, backticks, `**` and the targets of links.
+pub fn plain_text(lines: &[String]) -> Option<String> {
+    let mut paragraphs: Vec<String> = vec![];
+    let mut paragraph: Vec<String> = vec![];
+    let mut in_code_block = false;
+    for line in lines.iter().flat_map(|line| line.split('\n')) {
+        let line = line.trim();
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block || is_link_definition(line) {
+            continue;
+        }
+        if line.is_empty() {
+            if !paragraph.is_empty() {
+                paragraphs.push(paragraph.join(" "));
+                paragraph.clear();
+            }
+        } else {
+            paragraph.push(strip_markdown(line));
+        }
+    }
+    if !paragraph.is_empty() {
+        paragraphs.push(paragraph.join(" "));
+    }
+    if paragraphs.is_empty() {
+        None
+    } else {
+        Some(paragraphs.join("\n"))
+    }
+}
+
+// Splits the plain text into its first sentence (without the period) and the rest, if any.
+// The first sentence ends with the first paragraph.
+pub fn first_sentence(text: &str) -> (String, Option<String>) {
+    let paragraph_end = text.find('\n').unwrap_or(text.len());
+    let sentence_end = text[..paragraph_end]
+        .match_indices(". ")
+        .next()
+        .map(|(index, _)| index)
+        .unwrap_or(paragraph_end);
+    let sentence = text[..sentence_end].trim_end_matches('.').to_string();
+    let rest = text[sentence_end..].trim_start_matches('.').trim();
+    if rest.is_empty() {
+        (sentence, None)
+    } else {
+        (sentence, Some(rest.to_string()))
+    }
+}
+
+// `[name]: https://...`
+fn is_link_definition(line: &str) -> bool {
+    line.starts_with('[') && line.contains("]:")
+}
+
+// Keeps the text of `[text](url)`, `[text][reference]` and `[text]` links (and `![text](url)` images),
+// and removes backticks and `**`.
+fn strip_markdown(line: &str) -> String {
+    let mut text = String::new();
+    let mut rest = line;
+    while let Some(start) = rest.find('[') {
+        let end = match rest[start..].find(']') {
+            Some(end) => start + end,
+            None => break,
+        };
+        let before = &rest[..start];
+        text.push_str(before.strip_suffix('!').unwrap_or(before));
+        text.push_str(&rest[start + 1..end]);
+        rest = &rest[end + 1..];
+        let target_end = if rest.starts_with('(') {
+            rest.find(')')
+        } else if rest.starts_with('[') {
+            rest.find(']')
+        } else {
+            None
+        };
+        if let Some(target_end) = target_end {
+            rest = &rest[target_end + 1..];
+        }
+    }
+    text.push_str(rest);
+    text.replace("**", "").replace('`', "")
+}
@@ -1,18 +1,141 @@
-use crate::parse::{join_path, DeclarationArg, Property};
+use crate::docs;
+use crate::manifest::Json;
+use crate::parse::{
+    join_path, Alias, Body, Computed, DeclarationArg, Delegate, Options, Property, Settings,
+    Storage, StorageKind,
+};
+use crate::schema::SchemaFile;
 use proc_macro2::TokenStream as TS;
 use quote::{format_ident, quote};
-use std::collections::HashSet;
-use syn::{spanned::Spanned, Ident, Path};
+use std::collections::{HashMap, HashSet};
+use syn::{spanned::Spanned, Ident, Lit, LitStr, Path};
 
-pub fn property(id: usize, property: Property) -> (TS, Option<TS>, Option<TS>) {
-    let mut param_spec = ParamSpec::new(&property);
+// `dependents` are the computed properties which need to be notified
+// (in addition to the ones listed in `notifies`), when this property is set.
+// `conditions` holds the `#[cfg(...)]` of each property, which also applies to notifying it.
+// `id` matches the ids of the property and its aliases, which follow it in the returned ParamSpecs
+// (each with its row of the property table and its manifest entry).
+pub fn property(
+    id: &TS,
+    property: Property,
+    dependents: &[String],
+    conditions: &HashMap<String, TS>,
+    options: &Options,
+) -> (Vec<(TS, PropertyDocs)>, Option<TS>, Option<TS>) {
+    let mut param_spec = ParamSpec::new(&property, options);
+    let glib = options.glib();
+    let explicit_notifies = property.head.declaration.notifies();
+    let mut notifies: Vec<String> = explicit_notifies.iter().map(|name| name.value()).collect();
+    for dependent in dependents {
+        if !notifies.contains(dependent) {
+            notifies.push(dependent.clone());
+        }
+    }
+    // setting the property, or one of its aliases, changes all of them
+    if !property.aliases.is_empty() {
+        notifies.push(param_spec.name.clone());
+        notifies.extend(property.aliases.iter().map(|alias| alias.name.value()));
+    }
+    let aliases = property.aliases;
     let mut getter: Option<(Ident, TS)> = None;
     let mut setter: Option<(Ident, TS)> = None;
 
-    for block in property.blocks.0 {
+    let blocks = match property.body {
+        Body::Empty => {
+            property
+                .name
+                .span()
+                .unwrap()
+                .error(format!(
+                    "Property {:?} does not have an implementation",
+                    param_spec.name
+                ))
+                .help("Add `=> { get { ... } set { ... } }`, or use `#![interface]` to declare the properties of an interface")
+                .emit();
+            return (vec![param_spec.generate_documented()], None, None);
+        }
+        Body::Blocks(blocks) => blocks.0,
+        Body::Computed(computed) => {
+            let impl_block = computed.block;
+            getter = Some((computed.ident, quote! { #id => (#impl_block).to_value() }));
+            vec![]
+        }
+        Body::Storage(storage) => {
+            // storage can be read and written, unless the flags say otherwise
+            let (get, set) = storage_accessors(&storage, &param_spec);
+            let (readable, writable) = param_spec.explicit_access();
+            if readable || !writable {
+                getter = Some((storage.ident.clone(), quote! { #id => #get }));
+            }
+            if writable || !readable {
+                setter = Some((storage.ident.clone(), set));
+                // a `OnceCell` can only be set once, i.e. during construction
+                if storage.kind == StorageKind::Once {
+                    param_spec.flag_construct_only();
+                }
+            }
+            if options.default_from_struct {
+                param_spec.default_from_field(&storage);
+            }
+            vec![]
+        }
+        Body::Delegate(delegate) => {
+            let Delegate {
+                field,
+                property: child_property,
+                ..
+            } = &delegate;
+            let (readable, writable) = param_spec.explicit_access();
+            if readable || !writable {
+                getter = Some((
+                    delegate.ident.clone(),
+                    quote! { #id => self.#field.property_value(#child_property) },
+                ));
+            }
+            if writable || !readable {
+                setter = Some((
+                    delegate.ident.clone(),
+                    quote! { { self.#field.set_property_from_value(#child_property, value); } },
+                ));
+            }
+            param_spec.copy_from_child(&delegate);
+            vec![]
+        }
+        Body::Settings(settings) => {
+            if let Some((get, set)) = settings_accessors_for(&settings, &param_spec) {
+                let (readable, writable) = param_spec.explicit_access();
+                if readable || !writable {
+                    getter = Some((settings.ident.clone(), quote! { #id => #get }));
+                }
+                if writable || !readable {
+                    setter = Some((settings.ident.clone(), set));
+                }
+            } else {
+                return (vec![param_spec.generate_documented()], None, None);
+            }
+            // notified by the `changed` signal of the key (see `settings_hook`)
+            param_spec.flag_explicit_notify();
+            vec![]
+        }
+    };
+
+    let mut validator: Option<(Ident, TS)> = None;
+    for block in blocks {
         let name = block.name.to_string();
         let impl_block = block.block;
         match name.as_str() {
+            "validate" => {
+                if let Some((previous, _)) = &validator {
+                    block
+                        .name
+                        .span()
+                        .unwrap()
+                        .error("Duplicate 'validate'")
+                        .span_note(previous.span().unwrap(), "previous 'validate' was here")
+                        .emit();
+                }
+                validator = Some((block.name, quote! { #impl_block }));
+            }
             "get" => {
                 if getter.is_some() {
                     block
@@ -35,41 +158,526 @@ pub fn property(id: usize, property: Property) -> (TS, Option<TS>, Option<TS>) {
                         .span_note(setter.unwrap().0.span().unwrap(), "previous 'set' was here")
                         .emit();
                 }
-                setter = Some((block.name, quote! { #id => #impl_block }));
+                setter = Some((block.name, quote! { #impl_block }));
             }
             _ => panic!("Unsupported block: {name}"),
         }
     }
 
     match (getter.is_some(), setter.is_some()) {
+        (false, false) => panic!("At least one block ('get' or 'set') is required"),
+        // flags are taken from the overridden property
+        _ if param_spec.is_override => {}
         (true, false) => param_spec.flag_read_only(),
         (false, true) => param_spec.flag_write_only(),
         (true, true) => param_spec.flag_read_write(),
-        (false, false) => panic!("At least one block ('get' or 'set') is required"),
     }
 
-    (
-        param_spec.generate(),
-        getter.map(|(_, ts)| ts),
-        setter.map(|(_, ts)| ts),
+    if setter.is_none() {
+        if let Some(first) = explicit_notifies.first() {
+            first
+                .span()
+                .unwrap()
+                .error(format!(
+                    "Property {:?} notifies other properties, but does not have a 'set' block",
+                    param_spec.name
+                ))
+                .emit();
+        }
+    }
+
+    // a rejected value is not stored, and `notifies` and dependents are not notified
+    // (GObject still notifies the property itself, unless it is `explicit_notify`)
+    let setter = match (setter, validator) {
+        (Some((name, impl_block)), Some((_, validate_block))) => Some((
+            name,
+            quote! {
+                {
+                    let validation: Result<(), _> = #validate_block;
+                    if let Err(err) = validation {
+                        #glib::g_warning!(
+                            "GLib-GObject",
+                            "invalid value for property \"{}\" of type '{}': {}",
+                            pspec.name(),
+                            object.type_(),
+                            err
+                        );
+                        return;
+                    }
+                    #impl_block;
+                }
+            },
+        )),
+        (None, Some((name, _))) => {
+            name.span()
+                .unwrap()
+                .error(format!(
+                    "Property {:?} has a 'validate' block, but does not have a 'set' block",
+                    param_spec.name
+                ))
+                .emit();
+            None
+        }
+        (setter, None) => setter,
+    };
+    let setter = setter.map(|(name, impl_block)| (name, param_spec.clamp_value(impl_block)));
+
+    let setter = setter.map(|(_, impl_block)| {
+        if notifies.is_empty() {
+            quote! { #id => #impl_block }
+        } else {
+            // the block is evaluated first, so the dependent properties
+            // are only notified once the new value is stored.
+            let cfgs = notifies.iter().map(|name| conditions.get(name));
+            quote! {
+                #id => {
+                    #impl_block;
+                    #(#cfgs object.notify(#notifies);)*
+                }
+            }
+        }
+    });
+
+    let mut param_specs: Vec<(TS, PropertyDocs)> = aliases
+        .iter()
+        .map(|alias| param_spec.alias(alias).generate_documented())
+        .collect();
+    param_specs.insert(0, param_spec.generate_documented());
+    (param_specs, getter.map(|(_, ts)| ts), setter)
+}
+
+// Getter expression and setter block for a storage shorthand
+fn storage_accessors(storage: &Storage, param_spec: &ParamSpec) -> (TS, TS) {
+    let field = &storage.field;
+    let glib = &param_spec.glib;
+    let not_nullable_warning = not_nullable_warning(glib);
+    match storage.kind {
+        StorageKind::Cell => (
+            quote! { self.#field.get().to_value() },
+            quote! { { self.#field.set(value.get().unwrap()); } },
+        ),
+        // objects are always stored as `Option<T>`, strings only when nullable.
+        // `return` skips the notifications of `notifies` and dependents when the value was rejected
+        // (GObject still notifies the property itself, unless it is `explicit_notify`).
+        StorageKind::RefCell if param_spec.type_tag == "object" && !param_spec.nullable => (
+            quote! { self.#field.borrow().to_value() },
+            quote! {
+                {
+                    match value.get::<Option<_>>().unwrap() {
+                        Some(new_value) => {
+                            self.#field.replace(Some(new_value));
+                        }
+                        None => {
+                            #not_nullable_warning;
+                            return;
+                        }
+                    }
+                }
+            },
+        ),
+        StorageKind::RefCell if param_spec.type_tag == "string" && !param_spec.nullable => (
+            quote! { self.#field.borrow().to_value() },
+            quote! {
+                {
+                    match value.get::<Option<String>>().unwrap() {
+                        Some(new_value) => {
+                            self.#field.replace(new_value);
+                        }
+                        None => {
+                            #not_nullable_warning;
+                            return;
+                        }
+                    }
+                }
+            },
+        ),
+        StorageKind::RefCell => (
+            quote! { self.#field.borrow().to_value() },
+            quote! { { self.#field.replace(value.get().unwrap()); } },
+        ),
+        // `OnceCell<T>`, which is left empty when an object or string is constructed with `None`
+        StorageKind::Once => {
+            let store = quote! {
+                if self.#field.set(new_value).is_err() {
+                    #glib::g_critical!(
+                        "GLib-GObject",
+                        "property \"{}\" of type '{}' can only be set once",
+                        pspec.name(),
+                        object.type_()
+                    );
+                }
+            };
+            let set = if param_spec.type_tag == "object" || param_spec.type_tag == "string" {
+                quote! {
+                    {
+                        if let Some(new_value) = value.get::<Option<_>>().unwrap() {
+                            #store
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    {
+                        let new_value = value.get().unwrap();
+                        #store
+                    }
+                }
+            };
+            (
+                quote! {
+                    match self.#field.get() {
+                        Some(value) => value.to_value(),
+                        None => pspec.default_value().clone(),
+                    }
+                },
+                set,
+            )
+        }
+        // `glib::WeakRef<T>`, the getter returns `None` once the object is finalized
+        StorageKind::Weak => {
+            if param_spec.type_tag != "object" {
+                storage
+                    .ident
+                    .span()
+                    .unwrap()
+                    .error(format!(
+                        "Property {:?} of type '{}' cannot be stored as a weak reference",
+                        param_spec.name, param_spec.type_tag
+                    ))
+                    .note("Only object properties can use `weak(...)`")
+                    .emit();
+            }
+            let set = if param_spec.nullable {
+                quote! { { self.#field.set(value.get::<Option<_>>().unwrap().as_ref()); } }
+            } else {
+                quote! {
+                    {
+                        match value.get::<Option<_>>().unwrap() {
+                            Some(new_value) => {
+                                self.#field.set(Some(&new_value));
+                            }
+                            None => {
+                                #not_nullable_warning;
+                                return;
+                            }
+                        }
+                    }
+                }
+            };
+            (quote! { self.#field.upgrade().to_value() }, set)
+        }
+    }
+}
+
+fn not_nullable_warning(glib: &Path) -> TS {
+    quote! {
+        #glib::g_warning!(
+            "GLib-GObject",
+            "property \"{}\" of type '{}' is not nullable, and cannot be set to None",
+            pspec.name(),
+            object.type_()
+        )
+    }
+}
+
+// The `SettingsExt` accessors and the GVariant type of the key, for each supported type tag
+fn settings_accessors(type_tag: &str) -> Option<(Ident, Ident, &'static str)> {
+    let (getter, variant_type) = match type_tag {
+        "boolean" => ("boolean", "b"),
+        "int" => ("int", "i"),
+        "int64" => ("int64", "x"),
+        "double" => ("double", "d"),
+        "string" => ("string", "s"),
+        _ => return None,
+    };
+    Some((
+        format_ident!("{}", getter),
+        format_ident!("set_{}", getter),
+        variant_type,
+    ))
+}
+
+// Getter expression and setter block for a property stored in a GSettings key.
+// Writing a key fails e.g. if it is not writable, which is logged as a warning.
+fn settings_accessors_for(settings: &Settings, param_spec: &ParamSpec) -> Option<(TS, TS)> {
+    let Settings {
+        settings: expr,
+        key,
+        ..
+    } = settings;
+    let ParamSpec { glib, gio, .. } = param_spec;
+    let (getter, setter, _) = match settings_accessors(&param_spec.type_tag) {
+        Some(accessors) => accessors,
+        None => {
+            settings
+                .ident
+                .span()
+                .unwrap()
+                .error(format!(
+                    "Property {:?} of type '{}' cannot be stored in a settings key",
+                    param_spec.name, param_spec.type_tag
+                ))
+                .note("Supported types are boolean, int, int64, double and string")
+                .emit();
+            return None;
+        }
+    };
+    let set_value = if param_spec.type_tag == "string" {
+        let not_nullable_warning = not_nullable_warning(glib);
+        quote! {
+            let new_value: String = match value.get::<Option<String>>().unwrap() {
+                Some(new_value) => new_value,
+                None => {
+                    #not_nullable_warning;
+                    return;
+                }
+            };
+            let result = #gio::prelude::SettingsExt::#setter(&#expr, #key, &new_value);
+        }
+    } else {
+        quote! {
+            let result = #gio::prelude::SettingsExt::#setter(&#expr, #key, value.get().unwrap());
+        }
+    };
+    Some((
+        quote! { #gio::prelude::SettingsExt::#getter(&#expr, #key).to_value() },
+        quote! {
+            {
+                #set_value
+                if let Err(err) = result {
+                    #glib::g_warning!(
+                        "GLib-GObject",
+                        "property \"{}\" of type '{}' cannot be stored in settings key \"{}\": {}",
+                        pspec.name(),
+                        object.type_(),
+                        #key,
+                        err
+                    );
+                }
+            }
+        },
+    ))
+}
+
+// Statements for `constructed`, which check the type of the settings key, and
+// notify the property whenever the key changes.
+// With `#![schema = "..."]`, the key is checked against the schema file at compile time as well.
+pub fn settings_hook(
+    property: &Property,
+    options: &Options,
+    schema: Option<&SchemaFile>,
+) -> Option<TS> {
+    let settings = match &property.body {
+        Body::Settings(settings) => settings,
+        _ => return None,
+    };
+    let Settings {
+        settings: expr,
+        key,
+        ..
+    } = settings;
+    let name = property.name.value();
+    let aliases = property.aliases.iter().map(|alias| &alias.name);
+    let variant_type = settings_accessors(&property.head.declaration.tag)?.2;
+    if let Some(schema) = schema {
+        check_settings_key(property, key, variant_type, schema);
+    }
+    let glib = options.glib();
+    let gio = options.gio();
+    Some(quote! {
+        {
+            let settings = &#expr;
+            if let Some(schema) = #gio::prelude::SettingsExt::settings_schema(settings) {
+                if !schema.has_key(#key) {
+                    #glib::g_critical!(
+                        "GLib-GObject",
+                        "property \"{}\" of type '{}' is stored in settings key \"{}\", which does not exist",
+                        #name,
+                        object.type_(),
+                        #key
+                    );
+                } else if schema.key(#key).value_type().as_str() != #variant_type {
+                    #glib::g_critical!(
+                        "GLib-GObject",
+                        "property \"{}\" of type '{}' expects settings key \"{}\" of type '{}', not '{}'",
+                        #name,
+                        object.type_(),
+                        #key,
+                        #variant_type,
+                        schema.key(#key).value_type().as_str()
+                    );
+                }
+            }
+            let object_weak = object.downgrade();
+            #gio::prelude::SettingsExt::connect_changed(settings, Some(#key), move |_, _| {
+                if let Some(object) = object_weak.upgrade() {
+                    object.notify(#name);
+                    #(object.notify(#aliases);)*
+                }
+            });
+        }
+    })
+}
+
+fn check_settings_key(property: &Property, key: &LitStr, variant_type: &str, schema: &SchemaFile) {
+    let key_types = schema.key_types(&key.value());
+    if key_types.is_empty() {
+        key.span()
+            .unwrap()
+            .error(format!(
+                "Property {:?} is stored in settings key {:?}, which does not exist in {}",
+                property.name.value(),
+                key.value(),
+                schema.path
+            ))
+            .emit();
+    } else if !key_types.contains(&variant_type) {
+        key.span()
+            .unwrap()
+            .error(format!(
+                "Property {:?} of type '{}' needs a settings key of type '{}', but {:?} has type '{}' in {}",
+                property.name.value(),
+                property.head.declaration.tag,
+                variant_type,
+                key.value(),
+                key_types.join("', '"),
+                schema.path
+            ))
+            .emit();
+    }
+}
+
+// Properties of interfaces only have a ParamSpec, since interfaces have no instance storage.
+pub fn interface_property(property: Property, options: &Options) -> (TS, PropertyDocs) {
+    let mut param_spec = ParamSpec::new(&property, options);
+    for alias in &property.aliases {
+        alias
+            .ident
+            .span()
+            .unwrap()
+            .error(format!(
+                "Property {:?} is declared for an interface, and cannot have an alias",
+                param_spec.name
+            ))
+            .emit();
+    }
+    match property.body {
+        Body::Empty => {}
+        Body::Blocks(blocks) => {
+            for block in blocks.0 {
+                block
+                    .name
+                    .span()
+                    .unwrap()
+                    .error(format!(
+                        "Property {:?} is declared for an interface, and cannot have a '{}' block",
+                        param_spec.name, block.name
+                    ))
+                    .note("Interfaces have no instance storage, the property must be implemented by the objects implementing the interface")
+                    .emit();
+            }
+        }
+        Body::Storage(Storage { ident, .. })
+        | Body::Computed(Computed { ident, .. })
+        | Body::Delegate(Delegate { ident, .. })
+        | Body::Settings(Settings { ident, .. }) => {
+            ident
+                .span()
+                .unwrap()
+                .error(format!(
+                    "Property {:?} is declared for an interface, and cannot use `{}(...)`",
+                    param_spec.name, ident
+                ))
+                .emit();
+        }
+    }
+    if !param_spec.is_override {
+        param_spec.flag_interface();
+    }
+    param_spec.generate_documented()
+}
+
+// Whether `tag` selects a ParamSpec type (as opposed to being a flag)
+pub fn is_type_tag(tag: &str) -> bool {
+    matches!(
+        tag,
+        "boolean"
+            | "char"
+            | "double"
+            | "float"
+            | "int"
+            | "int64"
+            | "long"
+            | "string"
+            | "object"
+            | "enum"
+            | "override"
     )
 }
 
+#[derive(Clone)]
 enum FlagSource {
     Explicit(Path),
     Implied,
 }
 
+// The row of a property in the property table, and its entry in the manifest.
+// The row is only shown if the `#[cfg(...)]` condition of the property holds.
+pub struct PropertyDocs {
+    pub row: String,
+    pub condition: Option<TS>,
+    pub manifest_entry: Json,
+}
+
+#[derive(Clone)]
 struct ParamSpec {
     name: String,
+    // type tag (with the object, enum or interface type), flags, range and default value,
+    // for the property table in the docs
+    type_doc: String,
+    default_doc: Option<String>,
+    // nick taken from the doc comments
+    nick: Option<String>,
+    // for the manifest: the object, enum or interface type, the literal nick, blurb and default value,
+    // the property which an alias refers to, and the `#[cfg(...)]` condition
+    value_type: Option<String>,
+    explicit_nick: Option<String>,
+    explicit_blurb: Option<String>,
+    default_value: Option<Lit>,
+    alias_of: Option<String>,
+    condition: Option<TS>,
+    // the builder function, which takes the name (and `builder_type` for objects and enums,
+    // or with glib 0.16 the default value for enums)
     builder: TS,
+    builder_type: Option<TS>,
+    // since glib 0.16, the builders of objects and enums are generic over the type
+    glib_0_16: bool,
+    enum_type: Option<Path>,
     builder_steps: Vec<TS>,
     flags: Vec<(FlagSource, Flag)>,
     docs: Option<String>,
+    // `ParamSpecOverride`s take flags, nick and blurb from the overridden property
+    is_override: bool,
+    type_tag: String,
+    // the key of an explicit `default_value = ...`
+    explicit_default: Option<Path>,
+    // whether an object or string property accepts `None`
+    nullable: bool,
+    // the child field and property to copy nick, blurb, range and default value from
+    delegate: Option<(Ident, LitStr)>,
+    // whether values are clamped to `minimum` and `maximum`, before they are set
+    clamp: bool,
+    minimum: Option<Lit>,
+    maximum: Option<Lit>,
+    // function which translates nick and blurb, for translatable properties
+    gettext: Option<Path>,
+    // paths of the glib and gio crates
+    glib: Path,
+    gio: Path,
 }
 
 impl ParamSpec {
-    fn new(property: &Property) -> Self {
+    fn new(property: &Property, options: &Options) -> Self {
         let mut flags: Vec<(FlagSource, Flag)> = vec![];
         let mut builder_steps: Vec<TS> = vec![];
         let doc_strings = property
@@ -78,12 +686,16 @@ impl ParamSpec {
             .iter()
             .map(|doc| doc.value())
             .collect::<Vec<String>>();
-        let docs = if doc_strings.is_empty() {
-            None
-        } else {
-            Some(doc_strings.join("\n").trim_start().to_string())
-        };
+        let mut docs = docs::plain_text(&doc_strings);
         let name = property.name.value();
+        let mut is_override = false;
+        let mut explicit_default = None;
+        let mut nullable = false;
+        let mut clamp = None;
+        let mut minimum = None;
+        let mut maximum = None;
+        let mut builder_type = None;
+        let mut generic_enum = None;
 
         let mut args: Vec<DeclarationArg> = property
             .head
@@ -93,6 +705,19 @@ impl ParamSpec {
             .map(|args| args.args.iter().cloned().collect())
             .unwrap_or_default();
 
+        let mut type_doc = property.head.declaration.tag.clone();
+        let mut value_type = None;
+        if let Some(DeclarationArg::Tag(type_arg)) = args.first() {
+            if matches!(type_doc.as_str(), "object" | "enum" | "override") {
+                value_type = Some(join_path(type_arg));
+                type_doc = format!("{}({})", type_doc, join_path(type_arg));
+            }
+        }
+        let mut default_doc = None;
+        let mut default_value = None;
+        let mut explicit_nick = None;
+        let mut explicit_blurb = None;
+
         let builder = {
             let type_tag = property.head.declaration.tag.as_str();
             match type_tag {
@@ -102,7 +727,7 @@ impl ParamSpec {
                         &type_tag[0..1].to_uppercase(),
                         &type_tag[1..]
                     );
-                    quote! { #type_name::builder(#name) }
+                    quote! { #type_name::builder }
                 }
                 "object" => {
                     if args.len() == 0 {
@@ -115,52 +740,615 @@ impl ParamSpec {
                     } else {
                         panic!("Expected object type, not key/val")
                     };
-                    quote! { ParamSpecObject::builder(#name, #object_type::static_type()) }
+                    if options.glib_0_16 {
+                        quote! { ParamSpecObject::builder::<#object_type> }
+                    } else {
+                        builder_type = Some(quote! { #object_type::static_type() });
+                        quote! { ParamSpecObject::builder }
+                    }
+                }
+                "enum" => {
+                    if args.is_empty() {
+                        panic!("property of type 'enum' requires an enum type as first argument");
+                    }
+                    let enum_type = if let DeclarationArg::Tag(tag) = args.remove(0) {
+                        tag
+                    } else {
+                        panic!("Expected enum type, not key/val")
+                    };
+                    if options.glib_0_16 {
+                        let glib = options.glib();
+                        // the first value of the enum, unless the default value is given
+                        builder_type = Some(quote! {
+                            unsafe {
+                                #glib::translate::FromGlib::from_glib(
+                                    #glib::EnumClass::new(#enum_type::static_type()).unwrap().values()[0].value()
+                                )
+                            }
+                        });
+                        let builder = quote! { ParamSpecEnum::builder::<#enum_type> };
+                        generic_enum = Some(enum_type);
+                        builder
+                    } else {
+                        builder_type = Some(quote! { #enum_type::static_type() });
+                        quote! { ParamSpecEnum::builder }
+                    }
+                }
+                "override" => {
+                    if args.is_empty() {
+                        panic!("property of type 'override' requires the overridden interface (or class) as first argument");
+                    }
+                    let owner_type = if let DeclarationArg::Tag(tag) = args.remove(0) {
+                        tag
+                    } else {
+                        panic!("Expected interface or class type, not key/val")
+                    };
+                    let mut for_class = false;
+                    args.retain(|arg| match arg {
+                        DeclarationArg::Tag(tag) if join_path(tag) == "class" => {
+                            for_class = true;
+                            false
+                        }
+                        DeclarationArg::KeyList(_, _) => true,
+                        DeclarationArg::Tag(key) | DeclarationArg::KeyVal(key, _, _) => {
+                            key.span()
+                                .unwrap()
+                                .error(format!(
+                                    "Property {:?} overrides an inherited property, and cannot set {}",
+                                    name,
+                                    join_path(key)
+                                ))
+                                .note("Flags, nick and blurb are taken from the overridden property")
+                                .emit();
+                            false
+                        }
+                    });
+                    is_override = true;
+                    if for_class {
+                        quote! { ParamSpecOverride::for_class::<#owner_type> }
+                    } else {
+                        quote! { ParamSpecOverride::for_interface::<#owner_type> }
+                    }
                 }
                 _ => unimplemented!("not yet implemented: {}", type_tag),
             }
         };
 
+        // with `translatable` (or `#![translatable]`), nick and blurb are passed through gettext
+        let is_translatable = |arg: &DeclarationArg| matches!(arg, DeclarationArg::Tag(tag) if join_path(tag) == "translatable");
+        let gettext = if options.translatable || args.iter().any(is_translatable) {
+            Some(options.gettext())
+        } else {
+            None
+        };
+
         for arg in &args {
             match arg {
+                // not a ParamFlag, handled above
+                DeclarationArg::Tag(tag) if join_path(tag) == "translatable" => {}
+                // not a ParamFlag, only affects the storage shorthands
+                DeclarationArg::Tag(tag) if join_path(tag) == "nullable" => {
+                    let type_tag = &property.head.declaration.tag;
+                    if type_tag != "object" && type_tag != "string" {
+                        tag.span()
+                            .unwrap()
+                            .error(format!(
+                                "Property {:?} of type '{}' cannot be nullable",
+                                name, type_tag
+                            ))
+                            .note("Only object and string properties can be nullable")
+                            .emit();
+                    }
+                    nullable = true;
+                }
+                // not a ParamFlag either, but implies LAX_VALIDATION
+                DeclarationArg::Tag(tag) if join_path(tag) == "clamp" => {
+                    clamp = Some(tag.clone());
+                    flags.push((FlagSource::Implied, Flag::LaxValidation));
+                }
                 DeclarationArg::Tag(tag) => {
                     flags.push((FlagSource::Explicit(tag.clone()), Flag::from_path(&tag)));
                 }
                 DeclarationArg::KeyVal(key, _, value) => {
-                    builder_steps.push(quote! { .#key(#value) });
+                    match join_path(key).as_str() {
+                        "default_value" => {
+                            explicit_default = Some(key.clone());
+                            default_doc = Some(quote!(#value).to_string());
+                            default_value = Some(value.clone());
+                        }
+                        "minimum" => minimum = Some(value.clone()),
+                        "maximum" => maximum = Some(value.clone()),
+                        "nick" => explicit_nick = lit_string(value),
+                        "blurb" => explicit_blurb = lit_string(value),
+                        _ => {}
+                    }
+                    match (&gettext, value) {
+                        _ if generic_enum.is_some() && join_path(key) == "default_value" => {
+                            let glib = options.glib();
+                            builder_type = Some(quote! {
+                                unsafe { #glib::translate::FromGlib::from_glib(#value) }
+                            });
+                        }
+                        (Some(gettext), Lit::Str(_))
+                            if matches!(join_path(key).as_str(), "nick" | "blurb") =>
+                        {
+                            builder_steps.push(quote! { .#key(&#gettext(#value)) })
+                        }
+                        _ => builder_steps.push(quote! { .#key(#value) }),
+                    }
                 }
+                // handled in `property`
+                DeclarationArg::KeyList(key, _) if join_path(key) == "notifies" => {}
+                DeclarationArg::KeyList(key, _) => {
+                    key.span()
+                        .unwrap()
+                        .error(format!("Unsupported list argument: {}", join_path(key)))
+                        .emit();
+                }
+            }
+        }
+
+        // with `#![nick_from_docs]`, the first sentence is the nick, and the rest is the blurb
+        let has_nick = args.iter().any(
+            |arg| matches!(arg, DeclarationArg::KeyVal(key, _, _) if join_path(key) == "nick"),
+        );
+        let mut nick = None;
+        if options.nick_from_docs && !has_nick && !is_override {
+            if let Some(text) = docs.take() {
+                let (first_sentence, rest) = docs::first_sentence(&text);
+                nick = Some(first_sentence);
+                docs = Some(rest.unwrap_or(text));
             }
         }
 
-        ParamSpec {
+        let mut param_spec = ParamSpec {
             name,
+            type_doc,
+            default_doc,
+            nick,
+            value_type,
+            explicit_nick,
+            explicit_blurb,
+            default_value,
+            alias_of: None,
+            condition: property.head.condition(),
             builder,
+            builder_type,
+            glib_0_16: options.glib_0_16,
+            enum_type: generic_enum,
             builder_steps,
             flags,
             docs,
+            is_override,
+            type_tag: property.head.declaration.tag.clone(),
+            explicit_default,
+            nullable,
+            delegate: None,
+            clamp: false,
+            minimum,
+            maximum,
+            gettext,
+            glib: options.glib(),
+            gio: options.gio(),
+        };
+        if let Some(tag) = clamp {
+            param_spec.check_clamp(&tag);
         }
+        param_spec
+    }
+
+    // The ParamSpec of an alias is the one of the property, with another name and blurb.
+    // Construct flags are left out, so that the property is only set once during construction.
+    fn alias(&self, alias: &Alias) -> ParamSpec {
+        if self.is_override {
+            alias
+                .ident
+                .span()
+                .unwrap()
+                .error(format!(
+                    "Property {:?} overrides an inherited property, and cannot have an alias",
+                    self.name
+                ))
+                .emit();
+        }
+        let construct_only = self
+            .flags
+            .iter()
+            .any(|(_, flag)| *flag == Flag::ConstructOnly);
+        let mut param_spec = self.clone();
+        param_spec.name = alias.name.value();
+        param_spec.alias_of = Some(self.name.clone());
+        param_spec.flags = self
+            .flags
+            .iter()
+            .filter(|(_, flag)| !matches!(flag, Flag::Construct | Flag::ConstructOnly))
+            // the alias of a construct-only property can only be read
+            .filter_map(|(source, flag)| match flag {
+                Flag::Writable if construct_only => None,
+                Flag::Readwrite if construct_only => Some((source.clone(), Flag::Readable)),
+                _ => Some((source.clone(), *flag)),
+            })
+            .collect();
+        if alias.deprecated {
+            param_spec
+                .flags
+                .push((FlagSource::Implied, Flag::Deprecated));
+            param_spec.docs = Some(format!("Deprecated, use {:?} instead", self.name));
+        } else {
+            param_spec.docs = Some(format!("Alias of {:?}", self.name));
+        }
+        param_spec
+    }
+
+    // `clamp` needs a numeric type, and at least one bound
+    fn check_clamp(&mut self, tag: &Path) {
+        if self.clamp_type().is_none() {
+            tag.span()
+                .unwrap()
+                .error(format!(
+                    "Property {:?} of type '{}' cannot be clamped",
+                    self.name, self.type_tag
+                ))
+                .note("Only char, int, int64, long, float and double properties can be clamped")
+                .emit();
+        } else if self.minimum.is_none() && self.maximum.is_none() {
+            tag.span()
+                .unwrap()
+                .error(format!(
+                    "Property {:?} is clamped, but does not declare a minimum or maximum",
+                    self.name
+                ))
+                .help("Add `minimum = ...` and/or `maximum = ...`")
+                .emit();
+        } else {
+            self.clamp = true;
+        }
+    }
+
+    // the Rust type of numeric properties
+    fn clamp_type(&self) -> Option<TS> {
+        match self.type_tag.as_str() {
+            "char" => Some(quote! { i8 }),
+            "int" => Some(quote! { i32 }),
+            "int64" => Some(quote! { i64 }),
+            // a `glong` value can only be read as `ILong`
+            "long" => {
+                let glib = &self.glib;
+                Some(quote! { #glib::ILong })
+            }
+            "float" => Some(quote! { f32 }),
+            "double" => Some(quote! { f64 }),
+            _ => None,
+        }
+    }
+
+    // Shadows `value` in the setter with the clamped value
+    fn clamp_value(&self, impl_block: TS) -> TS {
+        if !self.clamp {
+            return impl_block;
+        }
+        let value_type = self.clamp_type();
+        let minimum = self.minimum.iter();
+        let maximum = self.maximum.iter();
+        // `ILong` is compared by the `c_long` within
+        let (inner, clamped) = if self.type_tag == "long" {
+            let glib = &self.glib;
+            (quote! { .0 }, quote! { #glib::ILong(new_value) })
+        } else {
+            (quote! {}, quote! { new_value })
+        };
+        quote! {
+            {
+                let value = &{
+                    let new_value: #value_type = value.get().unwrap();
+                    let new_value = new_value #inner #(.max(#minimum))* #(.min(#maximum))*;
+                    #clamped.to_value()
+                };
+                #impl_block;
+            }
+        }
+    }
+
+    // Row of the property table, see `doc_table`
+    fn doc_row(&self) -> String {
+        let flags = self.flag_names();
+        let lit = |lit: &Lit| quote!(#lit).to_string();
+        let mut range = match (&self.minimum, &self.maximum) {
+            (Some(minimum), Some(maximum)) => format!("{} ..= {}", lit(minimum), lit(maximum)),
+            (Some(minimum), None) => format!("{} ..", lit(minimum)),
+            (None, Some(maximum)) => format!("..= {}", lit(maximum)),
+            (None, None) => String::new(),
+        };
+        if self.clamp {
+            range.push_str(" (clamped)");
+        }
+        let description = match (&self.docs, &self.delegate) {
+            (Some(docs), _) => docs.replace('\n', " "),
+            (None, Some((field, property))) => {
+                format!("blurb of `{}` of `{}`", property.value(), field)
+            }
+            (None, None) => String::new(),
+        };
+        let cells = [
+            format!("`{}`", self.name),
+            self.type_doc.clone(),
+            flags.join(", "),
+            range,
+            self.default_doc.clone().unwrap_or_default(),
+            description,
+        ];
+        let cells: Vec<String> = cells.iter().map(|cell| cell.replace('|', "\\|")).collect();
+        format!("| {} |", cells.join(" | "))
+    }
+
+    // Entry of the property in the manifest, see `manifest::write`.
+    // Values which are only known at runtime (e.g. copied from a child) are null.
+    fn manifest_entry(&self) -> Json {
+        let lit = |lit: &Option<Lit>| lit.as_ref().map(Json::from_lit).unwrap_or(Json::Null);
+        // overrides take nick and blurb from the overridden property
+        let (nick, blurb) = if self.is_override {
+            (None, None)
+        } else {
+            (
+                self.explicit_nick.clone().or_else(|| self.nick.clone()),
+                self.explicit_blurb.clone().or_else(|| self.docs.clone()),
+            )
+        };
+        let flags = self
+            .flag_names()
+            .into_iter()
+            .map(|flag| Json::String(flag.to_string()))
+            .collect();
+        Json::Object(vec![
+            ("name", Json::String(self.name.clone())),
+            ("type", Json::String(self.type_tag.clone())),
+            ("value_type", Json::string_or_null(self.value_type.clone())),
+            ("flags", Json::Array(flags)),
+            ("nick", Json::string_or_null(nick)),
+            ("blurb", Json::string_or_null(blurb)),
+            ("minimum", lit(&self.minimum)),
+            ("maximum", lit(&self.maximum)),
+            ("clamp", Json::Bool(self.clamp)),
+            ("default", lit(&self.default_value)),
+            ("alias_of", Json::string_or_null(self.alias_of.clone())),
+            (
+                "cfg",
+                Json::string_or_null(self.condition.as_ref().map(TS::to_string)),
+            ),
+        ])
+    }
+
+    // each flag once, in the order they are given
+    fn flag_names(&self) -> Vec<&'static str> {
+        let mut seen_flags = HashSet::new();
+        self.flags
+            .iter()
+            .filter(|(_, flag)| seen_flags.insert(*flag))
+            .map(|(_, flag)| flag.name())
+            .collect()
+    }
+
+    fn generate_documented(self) -> (TS, PropertyDocs) {
+        let docs = PropertyDocs {
+            row: self.doc_row(),
+            condition: self.condition.clone(),
+            manifest_entry: self.manifest_entry(),
+        };
+        (self.generate(), docs)
     }
 
     fn generate(self) -> TS {
         let ParamSpec {
-            name: _,
+            name,
+            nick,
             builder,
+            builder_type,
             builder_steps,
             flags,
             docs,
+            is_override,
+            type_tag,
+            delegate,
+            gettext,
+            glib,
+            glib_0_16,
+            ..
         } = self;
+        let builder_type = builder_type.iter();
+        let builder = quote! { #builder(#name #(, #builder_type)*) };
+        if is_override {
+            return builder;
+        }
         let mut aspects = vec![];
         if flags.len() > 0 {
-            aspects.push(generate_flags(flags));
+            aspects.push(generate_flags(flags, &glib));
+        }
+        match (nick, &gettext) {
+            (Some(nick), Some(gettext)) => aspects.push(quote! { .nick(&#gettext(#nick)) }),
+            (Some(nick), None) => aspects.push(quote! { .nick(#nick) }),
+            (None, _) => {}
         }
         if let Some(blurb) = docs {
-            aspects.push(quote! { .blurb(#blurb) });
+            match gettext {
+                Some(gettext) => aspects.push(quote! { .blurb(&#gettext(#blurb)) }),
+                None => aspects.push(quote! { .blurb(#blurb) }),
+            }
+        } else if delegate.is_some() && glib_0_16 {
+            // since glib 0.16, the blurb is optional
+            aspects.push(quote! { .blurb(child_pspec.blurb().unwrap_or_default()) });
+        } else if delegate.is_some() {
+            aspects.push(quote! { .blurb(child_pspec.blurb()) });
         }
+        let (field, child_property) = match delegate {
+            Some(delegate) => delegate,
+            None => {
+                return quote! {
+                    #builder #(#aspects)* #(#builder_steps)* .build()
+                }
+            }
+        };
+
+        // explicit builder steps come last, so they take precedence over the copied values
+        let copied = match type_tag.as_str() {
+            "char" | "double" | "float" | "int" | "int64" | "long" => quote! {
+                .minimum(child_pspec.minimum())
+                .maximum(child_pspec.maximum())
+                .default_value(child_pspec.default_value())
+            },
+            // with glib 0.16, the default value of an enum is passed to the builder (see `copy_from_child`)
+            "enum" if glib_0_16 => quote! {},
+            "boolean" | "string" | "enum" => quote! {
+                .default_value(child_pspec.default_value())
+            },
+            _ => quote! {},
+        };
+        let downcast = match type_tag.as_str() {
+            "boolean" | "char" | "double" | "float" | "int" | "int64" | "long" | "string"
+            | "enum" => {
+                let type_name = format_ident!(
+                    "ParamSpec{}{}",
+                    &type_tag[0..1].to_uppercase(),
+                    &type_tag[1..]
+                );
+                let mismatch = format!(
+                    "Property {:?} is declared as '{}', but {:?} of `{}` is a {{}}",
+                    name,
+                    type_tag,
+                    child_property.value(),
+                    field
+                );
+                quote! {
+                    let child_pspec = child_pspec
+                        .downcast_ref::<#type_name>()
+                        .unwrap_or_else(|| panic!(#mismatch, child_pspec.type_()));
+                }
+            }
+            _ => quote! {},
+        };
+        let missing = format!(
+            "Property {:?} delegates to {:?} of `{}`, but '{{}}' does not have such a property",
+            name,
+            child_property.value(),
+            field
+        );
+        // The type of the child is taken from the field, which is either the child object itself,
+        // or dereferences to it, e.g. a `TemplateChild<T>`. The object is preferred by method resolution,
+        // since `DerefChild` is only implemented for a reference to `Child<T>`.
         quote! {
-            #builder #(#aspects)* #(#builder_steps)* .build()
+            {
+                struct Child<T>(std::marker::PhantomData<T>);
+                trait ObjectChild {
+                    fn child_type(&self) -> Type;
+                }
+                impl<T: StaticType> ObjectChild for Child<T> {
+                    fn child_type(&self) -> Type {
+                        T::static_type()
+                    }
+                }
+                trait DerefChild {
+                    fn child_type(&self) -> Type;
+                }
+                impl<T: std::ops::Deref> DerefChild for &Child<T>
+                where
+                    T::Target: StaticType,
+                {
+                    fn child_type(&self) -> Type {
+                        T::Target::static_type()
+                    }
+                }
+                fn child<S, T>(_: fn(&S) -> &T) -> Child<T> {
+                    Child(std::marker::PhantomData)
+                }
+                let child_class = object::ObjectClass::from_type(
+                    (&child(|this: &Self| &this.#field)).child_type()
+                ).unwrap();
+                let child_pspec = child_class
+                    .find_property(#child_property)
+                    .unwrap_or_else(|| panic!(#missing, child_class.type_()));
+                #downcast
+                let param_spec = #builder
+                    .nick(child_pspec.nick())
+                    #(#aspects)*
+                    #copied
+                    #(#builder_steps)*
+                    .build();
+                param_spec
+            }
         }
     }
 
+    // Copies nick, blurb, range and default value from the ParamSpec of the child property.
+    // This refers to `Self`, which `properties_fn` allows.
+    fn copy_from_child(&mut self, delegate: &Delegate) {
+        if self.is_override {
+            delegate
+                .ident
+                .span()
+                .unwrap()
+                .error(format!(
+                    "Property {:?} overrides an inherited property, and cannot be delegated",
+                    self.name
+                ))
+                .emit();
+            return;
+        }
+        self.delegate = Some((delegate.field.clone(), delegate.property.clone()));
+        if let (Some(enum_type), None) = (&self.enum_type, &self.explicit_default) {
+            self.builder_type = Some(quote! { child_pspec.default_value::<#enum_type>().unwrap() });
+        }
+        if self.default_doc.is_none() {
+            self.default_doc = Some(format!(
+                "from `{}` of `{}`",
+                delegate.property.value(),
+                delegate.field
+            ));
+        }
+    }
+
+    // Sets the default value to the one of the storage field in `Self::default()`,
+    // which is bound to `defaults` by `properties_fn`.
+    // Objects (and overrides) don't have a default value.
+    fn default_from_field(&mut self, storage: &Storage) {
+        if let Some(key) = &self.explicit_default {
+            key.span()
+                .unwrap()
+                .error(format!(
+                    "Property {:?} takes its default value from the struct, and cannot set default_value",
+                    self.name
+                ))
+                .help("Remove `default_value`, or change the value in the `Default` impl of the struct")
+                .emit();
+            return;
+        }
+        let field = &storage.field;
+        let value = match storage.kind {
+            StorageKind::Cell => quote! { defaults.#field.get() },
+            // `OnceCell`s are only set during construction
+            StorageKind::Once => return,
+            StorageKind::RefCell | StorageKind::Weak => quote! { defaults.#field.take() },
+        };
+        let step = match self.type_tag.as_str() {
+            "boolean" | "char" | "double" | "float" | "int" | "int64" | "long" => {
+                quote! { .default_value(#value) }
+            }
+            // for `String` as well as `Option<String>`
+            "string" => quote! { .default_value(Option::<String>::from(#value).as_deref()) },
+            // with glib 0.16, the default value is passed to the builder
+            "enum" if self.enum_type.is_some() => {
+                self.builder_type = Some(value);
+                self.default_doc = Some(format!("`Self::default().{}`", field));
+                return;
+            }
+            "enum" => {
+                let glib = &self.glib;
+                quote! { .default_value(#glib::translate::IntoGlib::into_glib(#value)) }
+            }
+            _ => return,
+        };
+        self.builder_steps.push(step);
+        self.default_doc = Some(format!("`Self::default().{}`", field));
+    }
+
     fn flag_read_only(&mut self) {
         self.check_flag_conflict(
             "set",
@@ -187,6 +1375,39 @@ impl ParamSpec {
         self.flags.push((FlagSource::Implied, Flag::Readwrite));
     }
 
+    fn flag_construct_only(&mut self) {
+        self.flags.push((FlagSource::Implied, Flag::ConstructOnly));
+    }
+
+    fn flag_explicit_notify(&mut self) {
+        self.flags.push((FlagSource::Implied, Flag::ExplicitNotify));
+    }
+
+    // without implementation blocks, READWRITE is implied, unless
+    // the declaration already says whether the property is readable and/or writable.
+    fn flag_interface(&mut self) {
+        let has_access_flag = self
+            .flags
+            .iter()
+            .any(|(_, flag)| matches!(flag, Flag::Readable | Flag::Writable | Flag::Readwrite));
+        if !has_access_flag {
+            self.flag_read_write();
+        }
+    }
+
+    // whether the explicit flags make the property readable and/or writable
+    fn explicit_access(&self) -> (bool, bool) {
+        let explicit = |f: fn(&Flag) -> bool| {
+            self.flags
+                .iter()
+                .any(|(source, flag)| matches!(source, FlagSource::Explicit(_)) && f(flag))
+        };
+        (
+            explicit(|flag| matches!(flag, Flag::Readable | Flag::Readwrite)),
+            explicit(|flag| matches!(flag, Flag::Writable | Flag::Readwrite)),
+        )
+    }
+
     fn check_flag_conflict(&self, block_name: &str, conflict: Option<&(FlagSource, Flag)>) {
         if let Some((FlagSource::Explicit(source), _)) = conflict {
             let flag_name = join_path(source);
@@ -206,7 +1427,7 @@ impl ParamSpec {
     }
 }
 
-fn generate_flags(flags: Vec<(FlagSource, Flag)>) -> TS {
+fn generate_flags(flags: Vec<(FlagSource, Flag)>, glib: &Path) -> TS {
     let mut seen_flags = HashSet::new();
     let flags: Vec<TS> = flags
         .into_iter()
@@ -218,7 +1439,7 @@ fn generate_flags(flags: Vec<(FlagSource, Flag)>) -> TS {
                 true
             }
         })
-        .map(|(_, flag)| flag.to_token_stream())
+        .map(|(_, flag)| flag.to_token_stream(glib))
         .collect();
     quote! { .flags(#(#flags)|*) }
 }
@@ -240,20 +1461,37 @@ enum Flag {
 }
 
 impl Flag {
-    fn to_token_stream(&self) -> TS {
+    fn name(&self) -> &'static str {
         match self {
-            Flag::Readable => quote! { glib::ParamFlags::READABLE },
-            Flag::Writable => quote! { glib::ParamFlags::WRITABLE },
-            Flag::Readwrite => quote! { glib::ParamFlags::READWRITE },
-            Flag::Construct => quote! { glib::ParamFlags::CONSTRUCT },
-            Flag::ConstructOnly => quote! { glib::ParamFlags::CONSTRUCT_ONLY },
-            Flag::LaxValidation => quote! { glib::ParamFlags::LAX_VALIDATION },
-            Flag::StaticName => quote! { glib::ParamFlags::STATIC_NAME },
-            Flag::Private => quote! { glib::ParamFlags::PRIVATE },
-            Flag::StaticNick => quote! { glib::ParamFlags::STATIC_NICK },
-            Flag::StaticBlurb => quote! { glib::ParamFlags::STATIC_BLURB },
-            Flag::ExplicitNotify => quote! { glib::ParamFlags::EXPLICIT_NOTIFY },
-            Flag::Deprecated => quote! { glib::ParamFlags::DEPRECATED },
+            Flag::Readable => "readable",
+            Flag::Writable => "writable",
+            Flag::Readwrite => "readwrite",
+            Flag::Construct => "construct",
+            Flag::ConstructOnly => "construct_only",
+            Flag::LaxValidation => "lax_validation",
+            Flag::StaticName => "static_name",
+            Flag::Private => "private",
+            Flag::StaticNick => "static_nick",
+            Flag::StaticBlurb => "static_blurb",
+            Flag::ExplicitNotify => "explicit_notify",
+            Flag::Deprecated => "deprecated",
+        }
+    }
+
+    fn to_token_stream(&self, glib: &Path) -> TS {
+        match self {
+            Flag::Readable => quote! { #glib::ParamFlags::READABLE },
+            Flag::Writable => quote! { #glib::ParamFlags::WRITABLE },
+            Flag::Readwrite => quote! { #glib::ParamFlags::READWRITE },
+            Flag::Construct => quote! { #glib::ParamFlags::CONSTRUCT },
+            Flag::ConstructOnly => quote! { #glib::ParamFlags::CONSTRUCT_ONLY },
+            Flag::LaxValidation => quote! { #glib::ParamFlags::LAX_VALIDATION },
+            Flag::StaticName => quote! { #glib::ParamFlags::STATIC_NAME },
+            Flag::Private => quote! { #glib::ParamFlags::PRIVATE },
+            Flag::StaticNick => quote! { #glib::ParamFlags::STATIC_NICK },
+            Flag::StaticBlurb => quote! { #glib::ParamFlags::STATIC_BLURB },
+            Flag::ExplicitNotify => quote! { #glib::ParamFlags::EXPLICIT_NOTIFY },
+            Flag::Deprecated => quote! { #glib::ParamFlags::DEPRECATED },
         }
     }
 
@@ -276,3 +1514,109 @@ impl Flag {
         }
     }
 }
+
+// Match arm for property ids that are not declared within `properties!`.
+// Following GObject convention (`G_OBJECT_WARN_INVALID_PROPERTY_ID`), a critical is logged
+// instead of aborting, unless the call should be chained up to the parent class.
+pub fn fallback_getter(options: &Options) -> TS {
+    let glib = options.glib();
+    let warning = invalid_property_warning(&glib);
+    let chain = if options.chain_to_parent {
+        let condition = chain_to_parent_condition(&glib);
+        quote! {
+            _ if #condition => unsafe {
+                use #glib::translate::*;
+                let parent_class = &*(<Self as #glib::subclass::types::ObjectSubclassType>::type_data()
+                    .as_ref()
+                    .parent_class() as *const #glib::gobject_ffi::GObjectClass);
+                let mut value = #glib::Value::from_type(pspec.value_type());
+                if let Some(f) = parent_class.get_property {
+                    let pspec: *mut #glib::gobject_ffi::GParamSpec = pspec.to_glib_none().0;
+                    f(
+                        object.as_ptr() as *mut #glib::gobject_ffi::GObject,
+                        (*pspec).param_id,
+                        value.to_glib_none_mut().0,
+                        pspec,
+                    );
+                }
+                value
+            }
+        }
+    } else {
+        quote! {}
+    };
+    quote! {
+        #chain
+        _ => {
+            #warning
+            #glib::Value::from_type(pspec.value_type())
+        }
+    }
+}
+
+// Same as `fallback_getter`, but for `set_property`.
+pub fn fallback_setter(options: &Options) -> TS {
+    let glib = options.glib();
+    let warning = invalid_property_warning(&glib);
+    let chain = if options.chain_to_parent {
+        let condition = chain_to_parent_condition(&glib);
+        quote! {
+            _ if #condition => unsafe {
+                use #glib::translate::*;
+                let parent_class = &*(<Self as #glib::subclass::types::ObjectSubclassType>::type_data()
+                    .as_ref()
+                    .parent_class() as *const #glib::gobject_ffi::GObjectClass);
+                if let Some(f) = parent_class.set_property {
+                    let pspec: *mut #glib::gobject_ffi::GParamSpec = pspec.to_glib_none().0;
+                    f(
+                        object.as_ptr() as *mut #glib::gobject_ffi::GObject,
+                        (*pspec).param_id,
+                        value.to_glib_none().0 as *mut _,
+                        pspec,
+                    );
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+    quote! {
+        #chain
+        _ => {
+            #warning
+        }
+    }
+}
+
+// Only properties installed by an ancestor can be chained up. The parent gets the id it installed
+// the property with (`param_id` of the pspec), since the id passed to this class is not its own.
+// GObject sends such properties to the ancestor by itself, so this only matters for direct calls
+// of `ObjectImpl::property` / `set_property`.
+fn chain_to_parent_condition(glib: &Path) -> TS {
+    quote! {
+        {
+            let type_ = <Self as #glib::subclass::types::ObjectSubclassType>::type_();
+            pspec.owner_type() != type_ && type_.is_a(pspec.owner_type())
+        }
+    }
+}
+
+fn invalid_property_warning(glib: &Path) -> TS {
+    quote! {
+        #glib::g_critical!(
+            "GLib-GObject",
+            "invalid property id {} for \"{}\" of type '{}' in '{}'",
+            id,
+            pspec.name(),
+            pspec.value_type(),
+            object.type_()
+        );
+    }
+}
+
+fn lit_string(lit: &Lit) -> Option<String> {
+    match lit {
+        Lit::Str(text) => Some(text.value()),
+        _ => None,
+    }
+}
@@ -0,0 +1,146 @@
+use crate::parse::{LooselySeparated, Property};
+use proc_macro2::{Group, Span, TokenStream as TS, TokenTree};
+use quote::quote;
+use syn::{
+    braced,
+    parse::{Parse, ParseStream, Parser},
+    Ident, Path, Result,
+};
+
+// `property_group!(Name { declarations })`
+pub struct PropertyGroup {
+    pub name: Ident,
+    pub declarations: TS,
+}
+
+impl Parse for PropertyGroup {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        let content;
+        braced!(content in input);
+        let declarations: TS = content.parse()?;
+        // Reports syntax errors where the group is declared, instead of where it is included
+        LooselySeparated::<Property>::parse.parse2(without_includes(declarations.clone()))?;
+        Ok(PropertyGroup { name, declarations })
+    }
+}
+
+// Declares the group as a `macro_rules!` macro of the same name.
+// It is invoked with the tokens of a `properties!` block before and after an `include`,
+// and expands to that block, with the declarations of the group in place of the `include`.
+// The block starts with `@group`, so that `properties!` knows to resolve its tokens at the call site.
+pub fn property_group(group: PropertyGroup) -> TS {
+    let PropertyGroup { name, declarations } = group;
+    quote! {
+        #[allow(unused_macros)]
+        macro_rules! #name {
+            ({ $($before:tt)* } { $($after:tt)* }) => {
+                ::gtk_properties_macro::properties! {
+                    @group
+                    $($before)*
+                    #declarations
+                    $($after)*
+                }
+            };
+        }
+    }
+}
+
+// Finds the first `include Name;` on the top level of a `properties!` block,
+// and invokes the group's macro with the tokens before and after it.
+pub fn expand_include(tokens: TS) -> Option<TS> {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    let start = tokens.iter().position(is_include)?;
+    let end = tokens[start..]
+        .iter()
+        .position(is_semicolon)
+        .map(|offset| start + offset)
+        .unwrap_or(tokens.len());
+    let path: TS = tokens[start + 1..end].iter().cloned().collect();
+    let before: TS = tokens[..start].iter().cloned().collect();
+    let after: TS = tokens[(end + 1).min(tokens.len())..]
+        .iter()
+        .cloned()
+        .collect();
+    Some(match syn::parse2::<Path>(path) {
+        Ok(path) => quote! { #path! { { #before } { #after } } },
+        Err(_) => syn::Error::new_spanned(
+            &tokens[start],
+            "Expected the name of a property group after `include`",
+        )
+        .to_compile_error(),
+    })
+}
+
+// The declarations of a group are expanded within its `macro_rules!` macro, so for hygiene,
+// `value`, `object` and `self` in there are different from the ones in the rest of the block.
+// Resolving all tokens at the call site (while keeping their location for error messages)
+// makes them refer to the bindings of the generated methods again.
+pub fn resolve_at_call_site(tokens: TS) -> TS {
+    tokens
+        .into_iter()
+        .map(|token| {
+            let span = token.span().resolved_at(Span::call_site());
+            match token {
+                TokenTree::Group(group) => {
+                    let mut respanned =
+                        Group::new(group.delimiter(), resolve_at_call_site(group.stream()));
+                    respanned.set_span(span);
+                    TokenTree::Group(respanned)
+                }
+                mut other => {
+                    other.set_span(span);
+                    other
+                }
+            }
+        })
+        .collect()
+}
+
+// Removes the `@group` markers at the start of a `properties!` block, which was expanded from a group
+// (once for each included group). Returns `None` for blocks which don't include a group.
+pub fn strip_group_marker(tokens: TS) -> Option<TS> {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut start = 0;
+    while is_group_marker(&tokens[start..]) {
+        start += 2;
+    }
+    if start == 0 {
+        None
+    } else {
+        Some(tokens[start..].iter().cloned().collect())
+    }
+}
+
+fn is_group_marker(tokens: &[TokenTree]) -> bool {
+    matches!(
+        tokens,
+        [TokenTree::Punct(at), TokenTree::Ident(group), ..] if at.as_char() == '@' && group == "group"
+    )
+}
+
+// Groups may include other groups, which are only resolved when the group itself is included.
+fn without_includes(tokens: TS) -> TS {
+    let mut skipping = false;
+    tokens
+        .into_iter()
+        .filter(|token| {
+            if is_include(token) {
+                skipping = true;
+            }
+            let keep = !skipping;
+            if skipping && is_semicolon(token) {
+                skipping = false;
+            }
+            keep
+        })
+        .collect()
+}
+
+fn is_include(token: &TokenTree) -> bool {
+    matches!(token, TokenTree::Ident(ident) if ident == "include")
+}
+
+fn is_semicolon(token: &TokenTree) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == ';')
+}
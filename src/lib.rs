@@ -1,57 +1,498 @@
 #![feature(proc_macro_diagnostic)]
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::parse_macro_input;
+use proc_macro2::TokenStream as TS;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{ParseStream, Parser},
+    parse_macro_input,
+    spanned::Spanned,
+    DeriveInput, Ident, Path,
+};
 
+mod dependencies;
+mod derive;
+mod docs;
 mod generate;
+mod group;
+mod manifest;
 mod parse;
+mod schema;
 
 #[proc_macro]
 pub fn properties(tokens: TokenStream) -> TokenStream {
+    if let Some(expanded) = group::expand_include(tokens.clone().into()) {
+        return TokenStream::from(expanded);
+    }
+    let tokens = match group::strip_group_marker(tokens.clone().into()) {
+        Some(tokens) => TokenStream::from(group::resolve_at_call_site(tokens)),
+        None => tokens,
+    };
     let properties = parse_macro_input!(tokens as parse::Properties);
+    let duplicates = dependencies::check_unique_names(&properties);
+
+    if properties.options.interface {
+        let options = &properties.options;
+        if let Some(method) = &properties.constructed {
+            method
+                .sig
+                .ident
+                .span()
+                .unwrap()
+                .error("`fn constructed` cannot be implemented for an interface")
+                .emit();
+        }
+        if let Some(ty) = &options.bind_settings {
+            ty.span()
+                .unwrap()
+                .error("`bind_settings` cannot be used for an interface")
+                .emit();
+        }
+        let (param_specs, docs): (Vec<TS>, Vec<generate::PropertyDocs>) = properties
+            .properties
+            .0
+            .into_iter()
+            .map(|property| {
+                let cfg = property.head.cfg_attribute();
+                let (param_spec, docs) = generate::interface_property(property, options);
+                (quote! { #cfg #param_spec }, docs)
+            })
+            .unzip();
+        let doc_table = doc_table(&docs);
+        if let Some(class) = &options.manifest {
+            manifest::write(class, true, manifest_entries(docs));
+        }
+        let properties_fn = properties_fn(
+            &format_ident!("properties"),
+            &param_specs,
+            &doc_table,
+            &options.glib(),
+            false,
+        );
+        return TokenStream::from(quote! { #duplicates #properties_fn });
+    }
+    if let Some(wrapper) = &properties.options.wrapper {
+        wrapper
+            .span()
+            .unwrap()
+            .error("The `wrapper` option is only supported by `#[derive(Properties)]`")
+            .note("The property table is part of the docs of `properties` instead")
+            .emit();
+    }
+
+    let (methods, _) = methods(
+        properties,
+        &Methods {
+            properties: format_ident!("properties"),
+            property: format_ident!("property"),
+            set_property: format_ident!("set_property"),
+            object_type: quote! { &Self::Type },
+        },
+    );
+    TokenStream::from(quote! { #duplicates #methods })
+}
+
+// Declares a group of properties, which can be included into `properties!` blocks with `include Name;`.
+#[proc_macro]
+pub fn property_group(tokens: TokenStream) -> TokenStream {
+    let group = parse_macro_input!(tokens as group::PropertyGroup);
+    TokenStream::from(group::property_group(group))
+}
+
+// Generates `derived_properties`, `derived_property` and `derived_set_property` methods
+// for the struct, from the fields marked with `#[property(...)]`.
+#[proc_macro_derive(Properties, attributes(property, properties))]
+pub fn derive_properties(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+    let properties = match derive::properties(&input) {
+        Ok(properties) => properties,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    let duplicates = dependencies::check_unique_names(&properties);
+    let glib = properties.options.glib();
+    let wrapper = properties.options.wrapper.clone();
+    let (methods, doc_table) = methods(
+        properties,
+        &Methods {
+            properties: format_ident!("derived_properties"),
+            property: format_ident!("derived_property"),
+            set_property: format_ident!("derived_set_property"),
+            object_type: quote! { &<Self as #glib::subclass::types::ObjectSubclass>::Type },
+        },
+    );
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    // an empty impl, so that the property table is shown in the docs of the wrapper type
+    let wrapper_docs = wrapper.map(|wrapper| {
+        quote! {
+            #doc_table
+            impl #wrapper {}
+        }
+    });
+    TokenStream::from(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            #methods
+        }
+
+        #duplicates
+
+        #wrapper_docs
+    })
+}
+
+// Implements `properties`, `property` and `set_property` within a `impl ObjectImpl` block,
+// by delegating to the methods generated by `#[derive(Properties)]`.
+// Options are given as in `#[properties(...)]`, e.g. `derived_properties!(crate = glib, glib_0_16)`.
+#[proc_macro]
+pub fn derived_properties(tokens: TokenStream) -> TokenStream {
+    let mut options = parse::Options::default();
+    let parser = |input: ParseStream| options.parse_list(input);
+    if let Err(err) = parser.parse(tokens) {
+        return TokenStream::from(err.to_compile_error());
+    }
+    let glib = options.glib();
+    let (object_param, object_arg) = if options.object_argument() {
+        (quote! { object: &Self::Type, }, quote! { object, })
+    } else {
+        (quote! {}, quote! {})
+    };
+    TokenStream::from(quote! {
+        fn properties() -> &'static [#glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn property(&self, #object_param id: usize, pspec: &#glib::ParamSpec) -> #glib::Value {
+            self.derived_property(#object_arg id, pspec)
+        }
+
+        fn set_property(&self, #object_param id: usize, value: &#glib::Value, pspec: &#glib::ParamSpec) {
+            self.derived_set_property(#object_arg id, value, pspec)
+        }
+    })
+}
+
+// Names and signature of the generated methods
+struct Methods {
+    properties: Ident,
+    property: Ident,
+    set_property: Ident,
+    object_type: TS,
+}
+
+// Returns the methods, and the property table for the docs.
+fn methods(properties: parse::Properties, methods: &Methods) -> (TS, TS) {
+    dependencies::check_notifies(&properties);
+    let dependents = dependencies::dependents(&properties);
+    let notify_conditions = dependencies::conditions(&properties);
+    let schema = properties
+        .options
+        .schema
+        .as_ref()
+        .and_then(schema::SchemaFile::load);
+    let mut settings_hooks: Vec<TS> = properties
+        .properties
+        .0
+        .iter()
+        .filter_map(|property| {
+            let hook = generate::settings_hook(property, &properties.options, schema.as_ref())?;
+            Some(match property.head.condition() {
+                Some(condition) => quote! { #[cfg(#condition)] { #hook } },
+                None => hook,
+            })
+        })
+        .collect();
+    // rebuilds the crate when the schema file changes
+    if let (Some(schema), false) = (&schema, settings_hooks.is_empty()) {
+        let path = &schema.path;
+        settings_hooks.insert(0, quote! { const _: &[u8] = include_bytes!(#path); });
+    }
 
-    let mut param_specs: Vec<proc_macro2::TokenStream> = vec![];
-    let mut getters: Vec<proc_macro2::TokenStream> = vec![];
-    let mut setters: Vec<proc_macro2::TokenStream> = vec![];
+    let mut param_specs: Vec<TS> = vec![];
+    let mut docs: Vec<generate::PropertyDocs> = vec![];
+    let mut getters: Vec<TS> = vec![];
+    let mut setters: Vec<TS> = vec![];
+    // Ids of properties following a `#[cfg(...)]` one depend on whether it is compiled,
+    // so they are declared as constants within `property` and `set_property`.
+    let mut getter_ids: Vec<TS> = vec![];
+    let mut setter_ids: Vec<TS> = vec![];
+    let mut conditions: Vec<TS> = vec![];
 
-    for (index, property) in properties.0 .0.into_iter().enumerate() {
-        let id = index + 1;
-        let (param_spec, getter, setter) = generate::property(id, property);
-        param_specs.push(param_spec);
+    // position of the next ParamSpec, each property is followed by its aliases
+    let mut position = 0;
+    for property in properties.properties.0 {
+        let cfg = property.head.cfg_attribute();
+        let condition = property.head.condition();
+        let mut ids: Vec<TS> = vec![];
+        let mut id_consts: Vec<TS> = vec![];
+        for _ in 0..=property.aliases.len() {
+            position += 1;
+            if conditions.is_empty() {
+                ids.push(quote! { #position });
+            } else {
+                let name = format_ident!("PROPERTY_ID_{}", position);
+                let unconditional = position - conditions.len();
+                id_consts.push(quote! {
+                    #cfg
+                    const #name: usize = #unconditional #(+ cfg!(#conditions) as usize)*;
+                });
+                ids.push(quote! { #name });
+            }
+            conditions.extend(condition.clone());
+        }
+        let id = quote! { #(#ids)|* };
+        let property_dependents = dependents
+            .get(&property.name.value())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let (property_param_specs, getter, setter) = generate::property(
+            &id,
+            property,
+            property_dependents,
+            &notify_conditions,
+            &properties.options,
+        );
+        for (param_spec, property_docs) in property_param_specs {
+            param_specs.push(quote! { #cfg #param_spec });
+            docs.push(property_docs);
+        }
         if let Some(getter) = getter {
-            getters.push(getter);
+            getters.push(quote! { #cfg #getter });
+            getter_ids.extend(id_consts.clone());
         }
         if let Some(setter) = setter {
-            setters.push(setter);
+            setters.push(quote! { #cfg #setter });
+            setter_ids.extend(id_consts);
         }
     }
 
-    TokenStream::from(quote! {
-        fn properties() -> &'static [gtk::glib::ParamSpec] {
-            use once_cell::sync::Lazy;
-            use gtk::glib::*;
-            static PROPERTIES: Lazy<Vec<ParamSpec>> = Lazy::new(|| {
-                vec![#(#param_specs),*]
-            });
-            PROPERTIES.as_ref()
+    let fallback_getter = generate::fallback_getter(&properties.options);
+    let fallback_setter = generate::fallback_setter(&properties.options);
+    let glib = properties.options.glib();
+    let doc_table = doc_table(&docs);
+    if let Some(class) = &properties.options.manifest {
+        manifest::write(class, false, manifest_entries(docs));
+    }
+    let properties_fn = properties_fn(
+        &methods.properties,
+        &param_specs,
+        &doc_table,
+        &glib,
+        properties.options.default_from_struct,
+    );
+    let Methods {
+        property,
+        set_property,
+        object_type,
+        ..
+    } = methods;
+
+    // Since glib 0.16, the object is not passed as an argument anymore.
+    // It is bound to `object` either way, so that the implementation blocks can use it.
+    let (object_param, object_arg, object_binding) = if properties.options.object_argument() {
+        (
+            quote! { object: #object_type, },
+            quote! { object },
+            quote! {},
+        )
+    } else {
+        (
+            quote! {},
+            quote! {},
+            quote! {
+                let object = #glib::subclass::prelude::ObjectSubclassExt::obj(self);
+                let object: #object_type = &object;
+            },
+        )
+    };
+
+    // With `bind_settings = Type`, the properties stored in settings are connected by `Type::bind_settings`,
+    // which the object calls from its own `constructed`. `properties!` is expanded within the `ObjectImpl`
+    // impl, so the inherent impl can only be nested within one of the generated methods.
+    let bind_settings = match &properties.options.bind_settings {
+        Some(ty) if settings_hooks.is_empty() => {
+            ty.span()
+                .unwrap()
+                .error("`bind_settings` is only used for properties stored in settings")
+                .emit();
+            quote! {}
+        }
+        Some(ty) => {
+            let object_type = quote! { &<#ty as #glib::subclass::types::ObjectSubclass>::Type };
+            let (object_param, object_binding) = if properties.options.object_argument() {
+                (quote! { object: #object_type }, quote! {})
+            } else {
+                (
+                    quote! {},
+                    quote! {
+                        let object = #glib::subclass::prelude::ObjectSubclassExt::obj(self);
+                        let object: #object_type = &object;
+                    },
+                )
+            };
+            quote! {
+                #[allow(non_local_definitions)]
+                impl #ty {
+                    fn bind_settings(&self, #object_param) {
+                        use #glib::prelude::*;
+                        #object_binding
+                        #(#settings_hooks)*
+                    }
+                }
+            }
         }
+        None => quote! {},
+    };
+    if properties.options.bind_settings.is_some() {
+        settings_hooks.clear();
+    }
+
+    // `constructed` connects the properties stored in settings. If the object implements `constructed` itself,
+    // it is declared within the block, and the settings are connected before its own statements.
+    let constructed = match properties.constructed {
+        Some(mut method) if !settings_hooks.is_empty() => {
+            let object_binding = if properties.options.object_argument() {
+                match constructed_object(&method) {
+                    Some(object) => quote! { let object: #object_type = #object; },
+                    None => {
+                        method
+                            .sig
+                            .span()
+                            .unwrap()
+                            .error("Expected `fn constructed(&self, object: &Self::Type)`")
+                            .note(
+                                "The object is needed to connect the properties stored in settings",
+                            )
+                            .emit();
+                        quote! {}
+                    }
+                }
+            } else {
+                object_binding.clone()
+            };
+            let statements = &method.block.stmts;
+            method.block = syn::parse_quote! {
+                {
+                    {
+                        use #glib::prelude::*;
+                        #object_binding
+                        #(#settings_hooks)*
+                    }
+                    #(#statements)*
+                }
+            };
+            quote! { #method }
+        }
+        Some(method) => quote! { #method },
+        None if settings_hooks.is_empty() => quote! {},
+        None => quote! {
+            fn constructed(&self, #object_param) {
+                use #glib::prelude::*;
+                #glib::subclass::object::ObjectImplExt::parent_constructed(self, #object_arg);
+                #object_binding
+                #(#settings_hooks)*
+            }
+        },
+    };
 
-        fn property(&self, object: &Self::Type, id: usize, pspec: &gtk::glib::ParamSpec) -> gtk::glib::Value {
-            use gtk::glib::prelude::*;
+    let methods = quote! {
+        #properties_fn
+
+        fn #property(&self, #object_param id: usize, pspec: &#glib::ParamSpec) -> #glib::Value {
+            #bind_settings
+            use #glib::prelude::*;
+            #object_binding
+            #(#getter_ids)*
             match id {
-                #(#getters),*
-                _ => unimplemented!()
+                #(#getters,)*
+                #fallback_getter
             }
         }
 
-        fn set_property(&self, object: &Self::Type, id: usize, value: &gtk::glib::Value, pspec: &gtk::glib::ParamSpec) {
-            use gtk::glib::prelude::*;
+        fn #set_property(&self, #object_param id: usize, value: &#glib::Value, pspec: &#glib::ParamSpec) {
+            use #glib::prelude::*;
+            #object_binding
+            #(#setter_ids)*
             match id {
-                #(#setters),*
-                _ => unimplemented!()
+                #(#setters,)*
+                #fallback_setter
             }
         }
-    })
+
+        #constructed
+    };
+    (methods, doc_table)
+}
+
+// Name of the object parameter of `fn constructed(&self, object: &Self::Type)`
+fn constructed_object(method: &syn::ImplItemMethod) -> Option<&Ident> {
+    match method.sig.inputs.iter().nth(1)? {
+        syn::FnArg::Typed(argument) => match &*argument.pat {
+            syn::Pat::Ident(pattern) => Some(&pattern.ident),
+            _ => None,
+        },
+        syn::FnArg::Receiver(_) => None,
+    }
+}
+
+// Markdown table of the properties, for the docs of the generated `properties` method
+// (and of the wrapper type, with `#[properties(wrapper = ...)]`).
+// One doc attribute per line of the table, so that the rows of `#[cfg(...)]` properties
+// can be left out with `cfg_attr` when the property is compiled out
+fn doc_table(docs: &[generate::PropertyDocs]) -> TS {
+    let header = "Properties:\n\n\
+                  | Name | Type | Flags | Range | Default | Description |\n\
+                  |------|------|-------|-------|---------|-------------|";
+    let rows = docs.iter().map(|property_docs| {
+        let row = &property_docs.row;
+        match &property_docs.condition {
+            Some(condition) => quote! { #[cfg_attr(#condition, doc = #row)] },
+            None => quote! { #[doc = #row] },
+        }
+    });
+    quote! {
+        #[doc = #header]
+        #(#rows)*
+    }
+}
+
+fn manifest_entries(docs: Vec<generate::PropertyDocs>) -> Vec<manifest::Json> {
+    docs.into_iter()
+        .map(|property_docs| property_docs.manifest_entry)
+        .collect()
+}
+
+// With `with_defaults`, the param specs can refer to `defaults` (i.e. `Self::default()`).
+// They are built within `get_or_init`, since `Self` cannot be used within the initializer of a static.
+fn properties_fn(
+    name: &Ident,
+    param_specs: &[TS],
+    doc_table: &TS,
+    glib: &Path,
+    with_defaults: bool,
+) -> TS {
+    let defaults = if with_defaults {
+        quote! { let defaults = Self::default(); }
+    } else {
+        quote! {}
+    };
+    // `OnceLock` needs Rust 1.70, the `once_cell` feature uses the `OnceCell` re-exported by glib instead
+    let once = if cfg!(feature = "once_cell") {
+        quote! { #glib::once_cell::sync::OnceCell }
+    } else {
+        quote! { std::sync::OnceLock }
+    };
+    quote! {
+        #doc_table
+        fn #name() -> &'static [#glib::ParamSpec] {
+            use #glib::*;
+            static PROPERTIES: #once<Vec<ParamSpec>> = #once::new();
+            PROPERTIES
+                .get_or_init(|| {
+                    #defaults
+                    vec![#(#param_specs),*]
+                })
+                .as_ref()
+        }
+    }
 }
 
 #[test]
@@ -0,0 +1,284 @@
+use std::collections::hash_map::{Entry, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use syn::{Lit, LitStr};
+
+// Environment variable with the directory of the manifests, used instead of `OUT_DIR`
+const MANIFEST_DIR: &str = "GTK_PROPERTIES_MANIFEST_DIR";
+
+// A JSON value, with just what the manifest needs.
+// Objects keep the order of their keys, so that the manifest follows the declarations.
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    pub fn string_or_null(value: Option<String>) -> Json {
+        value.map(Json::String).unwrap_or(Json::Null)
+    }
+
+    // Numbers and booleans keep their type, other literals become strings
+    pub fn from_lit(lit: &Lit) -> Json {
+        match lit {
+            Lit::Bool(lit) => Json::Bool(lit.value),
+            Lit::Int(lit) => Json::Number(lit.base10_digits().to_string()),
+            // JSON has no `1.`
+            Lit::Float(lit) => Json::Number(lit.base10_digits().trim_end_matches('.').to_string()),
+            Lit::Str(lit) => Json::String(lit.value()),
+            Lit::Char(lit) => Json::String(lit.value().to_string()),
+            Lit::Byte(lit) => Json::Number(lit.value().to_string()),
+            other => Json::String(quote::quote!(#other).to_string()),
+        }
+    }
+
+    fn write(&self, out: &mut String, indent: usize) {
+        let pad = |out: &mut String, indent: usize| out.push_str(&"  ".repeat(indent));
+        match self {
+            Json::Null => out.push_str("null"),
+            Json::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            Json::Number(value) => out.push_str(value),
+            Json::String(value) => write_string(out, value),
+            Json::Array(items) if items.is_empty() => out.push_str("[]"),
+            Json::Array(items) => {
+                out.push_str("[\n");
+                for (index, item) in items.iter().enumerate() {
+                    pad(out, indent + 1);
+                    item.write(out, indent + 1);
+                    out.push_str(if index + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                pad(out, indent);
+                out.push(']');
+            }
+            Json::Object(fields) if fields.is_empty() => out.push_str("{}"),
+            Json::Object(fields) => {
+                out.push_str("{\n");
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    pad(out, indent + 1);
+                    write_string(out, key);
+                    out.push_str(": ");
+                    value.write(out, indent + 1);
+                    out.push_str(if index + 1 < fields.len() {
+                        ",\n"
+                    } else {
+                        "\n"
+                    });
+                }
+                pad(out, indent);
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+// Writes `<class>.properties.json` with the entries of all properties, into the directory
+// given by `GTK_PROPERTIES_MANIFEST_DIR`, or else the `OUT_DIR` of the crate's build script.
+// Failures are reported at the `manifest = "..."` option.
+pub fn write(class: &LitStr, interface: bool, properties: Vec<Json>) {
+    let error = |message: String| class.span().unwrap().error(message);
+    let block = Block {
+        crate_name: std::env::var("CARGO_CRATE_NAME")
+            .or_else(|_| std::env::var("CARGO_PKG_NAME"))
+            .unwrap_or_default(),
+        file: class.span().unwrap().file(),
+    };
+    let written = manifest_dir()
+        .ok_or(Error::NoDirectory)
+        .and_then(|dir| write_manifest(&dir, &class.value(), &block, interface, properties));
+    match written {
+        Ok(()) => {}
+        Err(Error::NoDirectory) => error(format!(
+            "Cannot write the property manifest, neither {MANIFEST_DIR} nor OUT_DIR is set"
+        ))
+        .help(format!(
+            "Set {MANIFEST_DIR} (e.g. in `.cargo/config.toml`), or add a build script to the crate"
+        ))
+        .emit(),
+        Err(Error::Clash(path)) => error(format!(
+            "The property manifest {} is already written by another block with the class name {:?}",
+            path.display(),
+            class.value()
+        ))
+        .help("Each `manifest = \"...\"` needs a class name of its own")
+        .emit(),
+        Err(Error::Io(path, err)) => error(format!(
+            "Cannot write the property manifest {}: {}",
+            path.display(),
+            err
+        ))
+        .emit(),
+    }
+}
+
+// Where a `manifest = "..."` option is declared.
+// The macro may expand the blocks of several crates in one process (e.g. within rust-analyzer),
+// and expand the same block again after it was edited, so spans can't tell the blocks apart.
+struct Block {
+    crate_name: String,
+    file: String,
+}
+
+enum Error {
+    NoDirectory,
+    Clash(PathBuf),
+    Io(PathBuf, std::io::Error),
+}
+
+fn write_manifest(
+    dir: &Path,
+    class: &str,
+    block: &Block,
+    interface: bool,
+    properties: Vec<Json>,
+) -> Result<(), Error> {
+    let path = dir.join(format!("{class}.properties.json"));
+    if !claim(class, block) {
+        return Err(Error::Clash(path));
+    }
+    let manifest = Json::Object(vec![
+        ("class", Json::String(class.to_string())),
+        ("interface", Json::Bool(interface)),
+        ("properties", Json::Array(properties)),
+    ]);
+    let mut json = String::new();
+    manifest.write(&mut json, 0);
+    json.push('\n');
+    // leaves the file alone if nothing changed, so that its modification time stays meaningful
+    if std::fs::read_to_string(&path).ok().as_deref() == Some(json.as_str()) {
+        return Ok(());
+    }
+    std::fs::create_dir_all(dir)
+        .and_then(|_| std::fs::write(&path, json))
+        .map_err(|err| Error::Io(path, err))
+}
+
+// Records which file of the crate declares the class, returns `false` for a block in another file,
+// which would write the same manifest. Blocks within the same file are taken to be the same one, expanded again.
+fn claim(class: &str, block: &Block) -> bool {
+    static CLAIMED: Mutex<Option<HashMap<(String, String), String>>> = Mutex::new(None);
+    let mut claimed = CLAIMED.lock().unwrap_or_else(|err| err.into_inner());
+    match claimed
+        .get_or_insert_with(HashMap::new)
+        .entry((block.crate_name.clone(), class.to_string()))
+    {
+        Entry::Occupied(entry) => *entry.get() == block.file,
+        Entry::Vacant(entry) => {
+            entry.insert(block.file.clone());
+            true
+        }
+    }
+}
+
+// Not tracked by the compiler, so changing the variable alone doesn't cause the crate to be rebuilt
+fn manifest_dir() -> Option<PathBuf> {
+    std::env::var(MANIFEST_DIR)
+        .or_else(|_| std::env::var("OUT_DIR"))
+        .ok()
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_string(json: &Json) -> String {
+        let mut out = String::new();
+        json.write(&mut out, 0);
+        out
+    }
+
+    #[test]
+    fn escapes_strings() {
+        let json = Json::String("say \"hi\"\\\n\tnow\u{1}".to_string());
+        assert_eq!(to_string(&json), r#""say \"hi\"\\\n\tnow\u0001""#);
+    }
+
+    #[test]
+    fn float_without_fraction() {
+        let lit: Lit = syn::parse_quote!(1.);
+        assert_eq!(to_string(&Json::from_lit(&lit)), "1");
+        let lit: Lit = syn::parse_quote!(0.5);
+        assert_eq!(to_string(&Json::from_lit(&lit)), "0.5");
+    }
+
+    #[test]
+    fn empty_array_and_object() {
+        let json = Json::Object(vec![
+            ("flags", Json::Array(vec![])),
+            ("nested", Json::Object(vec![])),
+        ]);
+        assert_eq!(to_string(&json), "{\n  \"flags\": [],\n  \"nested\": {}\n}");
+    }
+
+    fn manifest_dir(test: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "gtk-properties-manifest-{}-{test}",
+            std::process::id()
+        ))
+    }
+
+    fn block(crate_name: &str, file: &str) -> Block {
+        Block {
+            crate_name: crate_name.to_string(),
+            file: file.to_string(),
+        }
+    }
+
+    #[test]
+    fn writes_into_manifest_dir() {
+        let dir = manifest_dir("writes");
+        let properties = vec![Json::Object(vec![(
+            "name",
+            Json::String("clicks".to_string()),
+        )])];
+        let block = block("app", "src/button.rs");
+        assert!(write_manifest(&dir, "MyButton", &block, false, properties).is_ok());
+        let json = std::fs::read_to_string(dir.join("MyButton.properties.json")).unwrap();
+        assert_eq!(
+            json,
+            "{\n  \"class\": \"MyButton\",\n  \"interface\": false,\n  \"properties\": [\n    {\n      \"name\": \"clicks\"\n    }\n  ]\n}\n"
+        );
+    }
+
+    #[test]
+    fn reports_clashing_class_names() {
+        let dir = manifest_dir("clashes");
+        let first = block("app", "src/label.rs");
+        assert!(write_manifest(&dir, "MyLabel", &first, false, vec![]).is_ok());
+        assert!(write_manifest(&dir, "MyLabel", &first, false, vec![]).is_ok());
+        assert!(matches!(
+            write_manifest(&dir, "MyLabel", &block("app", "src/other.rs"), true, vec![]),
+            Err(Error::Clash(_))
+        ));
+    }
+
+    #[test]
+    fn class_names_are_per_crate() {
+        let app = manifest_dir("app");
+        let library = manifest_dir("library");
+        let block_in = |crate_name| block(crate_name, "src/entry.rs");
+        assert!(write_manifest(&app, "MyEntry", &block_in("app"), false, vec![]).is_ok());
+        assert!(write_manifest(&library, "MyEntry", &block_in("library"), false, vec![]).is_ok());
+    }
+}
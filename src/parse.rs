@@ -1,35 +1,397 @@
+use proc_macro2::TokenStream as TS;
+use quote::quote;
 use syn::{
-    braced, parenthesized,
-    parse::{Parse, ParseStream},
+    braced, bracketed,
+    ext::IdentExt,
+    parenthesized,
+    parse::{Parse, ParseStream, Parser},
+    parse_quote,
     punctuated::Punctuated,
-    token, Attribute, Ident, Lit, LitStr, Path, Result, Token,
+    token, AttrStyle, Attribute, Expr, Ident, ImplItemMethod, Lit, LitStr, Meta, NestedMeta, Path,
+    Result, Token,
 };
 
-pub struct Properties(pub LooselySeparated<Property>);
+pub struct Properties {
+    pub options: Options,
+    pub properties: LooselySeparated<Property>,
+    // the object's own `constructed`, which the settings hooks are added to
+    pub constructed: Option<ImplItemMethod>,
+}
 
 impl Parse for Properties {
     fn parse(input: ParseStream) -> Result<Self> {
-        Ok(Properties(input.parse()?))
+        let options = input.parse()?;
+        let entries: LooselySeparated<Entry> = input.parse()?;
+        let mut properties = vec![];
+        let mut constructed: Option<ImplItemMethod> = None;
+        for entry in entries.0 {
+            match entry {
+                Entry::Property(property) => properties.push(property),
+                Entry::Method(method) if method.sig.ident != "constructed" => {
+                    return Err(syn::Error::new(
+                        method.sig.ident.span(),
+                        "Only `fn constructed` can be implemented within `properties!`",
+                    ))
+                }
+                Entry::Method(method) if constructed.is_some() => {
+                    return Err(syn::Error::new(
+                        method.sig.ident.span(),
+                        "Duplicate `fn constructed`",
+                    ))
+                }
+                Entry::Method(method) => constructed = Some(method),
+            }
+        }
+        Ok(Properties {
+            options,
+            properties: LooselySeparated(properties),
+            constructed,
+        })
+    }
+}
+
+// a property declaration, or `fn constructed(...) { ... }`
+enum Entry {
+    Property(Property),
+    Method(ImplItemMethod),
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        // skips the attributes, which can't be parsed as `Attribute`s, e.g. `#[enum(Mode)]`
+        let fork = input.fork();
+        while fork.peek(Token![#]) {
+            let _: Token![#] = fork.parse()?;
+            let _content;
+            bracketed!(_content in fork);
+        }
+        if fork.peek(Token![fn]) {
+            Ok(Entry::Method(input.parse()?))
+        } else {
+            Ok(Entry::Property(input.parse()?))
+        }
+    }
+}
+
+// Options that apply to the entire `properties!` block.
+// They are given as inner attributes, e.g. `#![chain_to_parent]`.
+#[derive(Default)]
+pub struct Options {
+    // chain unknown property ids up to the parent class, instead of logging a critical
+    pub chain_to_parent: bool,
+    // declare properties of an interface (only generates `fn properties`)
+    pub interface: bool,
+    // take the default values of storage shorthands from `Self::default()`
+    pub default_from_struct: bool,
+    // path of the glib crate, e.g. `#![crate = glib]`
+    pub crate_path: Option<Path>,
+    // generate the `ObjectImpl` signatures of glib 0.16 and later, which don't pass the object
+    pub glib_0_16: bool,
+    // translate the nick and blurb of all properties
+    pub translatable: bool,
+    // use the first sentence of the doc comments as nick, unless there is a `nick = ...`
+    pub nick_from_docs: bool,
+    // wrapper type, which gets the property table in its docs (only for `#[derive(Properties)]`)
+    pub wrapper: Option<Path>,
+    // function which translates a string, e.g. `#![gettext = gettextrs::gettext]`
+    pub gettext: Option<Path>,
+    // class name of the JSON manifest of the properties, e.g. `#![manifest = "MyButton"]`
+    pub manifest: Option<LitStr>,
+    // `.gschema.xml` file which the keys of `settings(...)` properties are checked against
+    pub schema: Option<LitStr>,
+    // type which gets a `bind_settings` method, instead of implementing `constructed`,
+    // e.g. `#![bind_settings = MyObject]`
+    pub bind_settings: Option<Path>,
+}
+
+impl Parse for Options {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut options = Options::default();
+        for attr in input.call(Attribute::parse_inner)? {
+            let parser = |input: ParseStream| options.parse_option(&attr.path, input);
+            parser.parse2(attr.tokens.clone())?;
+        }
+        Ok(options)
+    }
+}
+
+impl Options {
+    // Comma separated options, e.g. `default_from_struct, crate = glib`
+    pub fn parse_list(&mut self, input: ParseStream) -> Result<()> {
+        while !input.is_empty() {
+            let name = input.call(Path::parse_mod_style)?;
+            self.parse_option(&name, input)?;
+            if !input.is_empty() {
+                let _: Token![,] = input.parse()?;
+            }
+        }
+        Ok(())
+    }
+
+    // The option `name`, followed by its arguments (if any) in `input`
+    fn parse_option(&mut self, name: &Path, input: ParseStream) -> Result<()> {
+        let option = join_path(name);
+        if option == "crate" {
+            let _: Token![=] = input.parse()?;
+            self.crate_path = Some(input.parse()?);
+            return Ok(());
+        }
+        if option == "gettext" {
+            let _: Token![=] = input.parse()?;
+            self.gettext = Some(input.parse()?);
+            return Ok(());
+        }
+        if option == "manifest" {
+            let _: Token![=] = input.parse()?;
+            self.manifest = Some(input.parse()?);
+            return Ok(());
+        }
+        if option == "schema" {
+            let _: Token![=] = input.parse()?;
+            self.schema = Some(input.parse()?);
+            return Ok(());
+        }
+        if option == "bind_settings" {
+            let _: Token![=] = input.parse()?;
+            self.bind_settings = Some(input.parse()?);
+            return Ok(());
+        }
+        if option == "wrapper" {
+            let _: Token![=] = input.parse()?;
+            self.wrapper = Some(input.parse()?);
+            return Ok(());
+        }
+        if !input.is_empty() && !input.peek(Token![,]) {
+            return Err(input.error(format!("`{option}` does not take any arguments")));
+        }
+        match option.as_str() {
+            "chain_to_parent" => self.chain_to_parent = true,
+            "interface" => self.interface = true,
+            "default_from_struct" => self.default_from_struct = true,
+            "glib_0_16" => self.glib_0_16 = true,
+            "translatable" => self.translatable = true,
+            "nick_from_docs" => self.nick_from_docs = true,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    format!("Unknown option: {option}"),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    // Path of the glib crate in the generated code. Unless given with `crate = ...`,
+    // it depends on the enabled features: `gtk::glib` for "gtk3" and "gtk4",
+    // `glib` for "glib" (without "gtk3" or "gtk4").
+    pub fn glib(&self) -> Path {
+        match &self.crate_path {
+            Some(path) => path.clone(),
+            None => parse_quote!(gtk::glib),
+        }
+    }
+
+    // Whether `property`, `set_property` and `constructed` take the object as an argument (as before glib 0.16),
+    // or get it from `self.obj()`. The latter is selected with `glib_0_16`.
+    pub fn object_argument(&self) -> bool {
+        !self.glib_0_16
+    }
+
+    // Function which translates the nick and blurb of translatable properties
+    pub fn gettext(&self) -> Path {
+        match &self.gettext {
+            Some(path) => path.clone(),
+            None => parse_quote!(gettextrs::gettext),
+        }
+    }
+
+    // Path of the gio crate, next to the glib crate (i.e. `gtk::gio` for `gtk::glib`)
+    pub fn gio(&self) -> Path {
+        let mut path = self.glib();
+        if let Some(last) = path.segments.last_mut() {
+            last.ident = Ident::new("gio", last.ident.span());
+        }
+        path
     }
 }
 
 pub struct Property {
     pub head: Head,
     pub name: LitStr,
-    pub arrow: Token![=>],
-    pub brace: token::Brace,
-    pub blocks: LooselySeparated<Block>,
+    pub aliases: Vec<Alias>,
+    pub arrow: Option<Token![=>]>,
+    pub body: Body,
 }
 
 impl Parse for Property {
     fn parse(input: ParseStream) -> Result<Self> {
-        let content;
+        let head = input.parse()?;
+        let name = input.parse()?;
+        let mut aliases = vec![];
+        while input.peek(Ident) && input.fork().parse::<Ident>()? == "alias" {
+            aliases.push(input.parse()?);
+        }
+        let arrow: Option<Token![=>]> = input.parse()?;
+        let body = if arrow.is_some() {
+            input.parse()?
+        } else {
+            Body::Empty
+        };
         Ok(Property {
-            head: input.parse()?,
-            name: input.parse()?,
-            arrow: input.parse()?,
-            brace: braced!(content in input),
-            blocks: content.parse()?,
+            head,
+            name,
+            aliases,
+            arrow,
+            body,
+        })
+    }
+}
+
+// `alias "old-name"` or `alias "old-name" (deprecated)`, following the property name
+pub struct Alias {
+    pub ident: Ident,
+    pub name: LitStr,
+    pub deprecated: bool,
+}
+
+impl Parse for Alias {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident = input.parse()?;
+        let name = input.parse()?;
+        let mut deprecated = false;
+        if input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            let flag: Ident = content.parse()?;
+            if flag != "deprecated" {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    format!("Unknown alias flag: {flag}, expected `deprecated`"),
+                ));
+            }
+            deprecated = true;
+        }
+        Ok(Alias {
+            ident,
+            name,
+            deprecated,
+        })
+    }
+}
+
+// everything after the `=>` of a property declaration
+pub enum Body {
+    // no `=>` at all (for properties declared by interfaces)
+    Empty,
+    // `{ get { ... } set { ... } }`
+    Blocks(LooselySeparated<Block>),
+    // `computed(dependency, ...) { ... }`
+    Computed(Computed),
+    // `cell(field)`, `refcell(field)`, `weak(field)`, `once(field)`
+    Storage(Storage),
+    // `delegate(child_field, "child-property")`
+    Delegate(Delegate),
+    // `settings(self.settings, "key")`
+    Settings(Settings),
+}
+
+impl Parse for Body {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(token::Brace) {
+            let content;
+            braced!(content in input);
+            Ok(Body::Blocks(content.parse()?))
+        } else {
+            let ident: Ident = input.fork().parse()?;
+            match ident.to_string().as_str() {
+                "computed" => Ok(Body::Computed(input.parse()?)),
+                _ if StorageKind::from_ident(&ident).is_some() => Ok(Body::Storage(input.parse()?)),
+                "delegate" => Ok(Body::Delegate(input.parse()?)),
+                "settings" => Ok(Body::Settings(input.parse()?)),
+                _ => Err(syn::Error::new(
+                    ident.span(),
+                    format!("Expected implementation block, `computed(...)`, `delegate(...)`, `settings(...)` or a storage shorthand, found `{ident}`"),
+                )),
+            }
+        }
+    }
+}
+
+// a read-only property, whose value is derived from other properties
+pub struct Computed {
+    pub ident: Ident,
+    pub _paren: token::Paren,
+    pub dependencies: Punctuated<Ident, Token![,]>,
+    pub block: syn::Block,
+}
+
+impl Computed {
+    pub fn dependency_names(&self) -> Vec<(String, &Ident)> {
+        self.dependencies
+            .iter()
+            .map(|ident| (property_name(ident), ident))
+            .collect()
+    }
+}
+
+// properties referred to by identifiers (dependencies, struct fields) use `-` in place of `_`,
+// i.e. `page_count` refers to "page-count"
+pub fn property_name(ident: &Ident) -> String {
+    ident.to_string().replace('_', "-")
+}
+
+impl Parse for Computed {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        Ok(Computed {
+            ident: input.parse()?,
+            _paren: parenthesized!(content in input),
+            dependencies: Punctuated::parse_terminated(&content)?,
+            block: input.parse()?,
+        })
+    }
+}
+
+// a property which is forwarded to a property of a child object
+pub struct Delegate {
+    pub ident: Ident,
+    pub _paren: token::Paren,
+    pub field: Ident,
+    pub _comma: Token![,],
+    pub property: LitStr,
+}
+
+impl Parse for Delegate {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        Ok(Delegate {
+            ident: input.parse()?,
+            _paren: parenthesized!(content in input),
+            field: content.parse()?,
+            _comma: content.parse()?,
+            property: content.parse()?,
+        })
+    }
+}
+
+// a property which is stored in a GSettings key
+pub struct Settings {
+    pub ident: Ident,
+    pub _paren: token::Paren,
+    pub settings: Expr,
+    pub _comma: Token![,],
+    pub key: LitStr,
+}
+
+impl Parse for Settings {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        Ok(Settings {
+            ident: input.parse()?,
+            _paren: parenthesized!(content in input),
+            settings: content.parse()?,
+            _comma: content.parse()?,
+            key: content.parse()?,
         })
     }
 }
@@ -48,21 +410,52 @@ impl Parse for DocString {
     }
 }
 
+// value of a `#[doc = "..."]` attribute
+pub fn parse_doc(attr: Attribute) -> Result<LitStr> {
+    let doc_string: DocString = syn::parse2(attr.tokens)?;
+    Ok(doc_string.value)
+}
+
 pub struct Head {
     pub doc: Vec<LitStr>,
     pub declaration: Declaration,
+    // conditions of `#[cfg(...)]` and `#[cfg_attr(..., cfg(...))]` attributes
+    pub cfg: Vec<NestedMeta>,
+}
+
+impl Head {
+    // The condition under which the property is compiled, if any
+    pub fn condition(&self) -> Option<TS> {
+        match self.cfg.as_slice() {
+            [] => None,
+            [condition] => Some(quote! { #condition }),
+            conditions => Some(quote! { all(#(#conditions),*) }),
+        }
+    }
+
+    // `#[cfg(...)]` for each item generated for the property
+    pub fn cfg_attribute(&self) -> TS {
+        match self.condition() {
+            Some(condition) => quote! { #[cfg(#condition)] },
+            None => quote! {},
+        }
+    }
 }
 
 impl Parse for Head {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut doc = vec![];
+        let mut cfg = vec![];
         let mut declaration = None;
-        let attrs: Vec<Attribute> = input.call(Attribute::parse_outer)?;
+        let attrs: Vec<Attribute> = input.call(parse_outer_attributes)?;
         for attr in attrs {
             let path = join_path(&attr.path);
             if path.as_str() == "doc" {
-                let doc_string: DocString = syn::parse2(attr.tokens)?;
-                doc.push(doc_string.value);
+                doc.push(parse_doc(attr)?);
+            } else if path.as_str() == "cfg" {
+                cfg.push(attr.parse_args()?);
+            } else if path.as_str() == "cfg_attr" {
+                cfg.extend(parse_cfg_attr(&attr)?);
             } else {
                 if declaration.is_some() {
                     let span = attr.path.segments.iter().next().unwrap().ident.span();
@@ -87,11 +480,57 @@ impl Parse for Head {
             Ok(Head {
                 doc,
                 declaration: declaration.unwrap(),
+                cfg,
             })
         }
     }
 }
 
+// `#[cfg_attr(predicate, cfg(condition))]` compiles the property if
+// `any(not(predicate), condition)` holds. Other attributes cannot be applied conditionally,
+// since the declaration has to be known when the macro is expanded.
+fn parse_cfg_attr(attr: &Attribute) -> Result<Vec<NestedMeta>> {
+    let args = attr.parse_args_with(Punctuated::<NestedMeta, Token![,]>::parse_terminated)?;
+    let mut args = args.into_iter();
+    let predicate = args
+        .next()
+        .ok_or_else(|| syn::Error::new_spanned(attr, "Expected a predicate in `cfg_attr`"))?;
+    args.map(|arg| match arg {
+        NestedMeta::Meta(Meta::List(list))
+            if list.path.is_ident("cfg") && list.nested.len() == 1 =>
+        {
+            let condition = &list.nested[0];
+            Ok(parse_quote! { any(not(#predicate), #condition) })
+        }
+        other => Err(syn::Error::new_spanned(
+            other,
+            "Only `cfg(...)` can be applied with `cfg_attr` on a property declaration",
+        )),
+    })
+    .collect()
+}
+
+// Like `Attribute::parse_outer`, but also accepts the `override` and `enum` keywords
+// as attribute names (i.e. `#[override(gtk::Orientable)]`).
+fn parse_outer_attributes(input: ParseStream) -> Result<Vec<Attribute>> {
+    let mut attrs = vec![];
+    while input.peek(Token![#]) {
+        let content;
+        attrs.push(Attribute {
+            pound_token: input.parse()?,
+            style: AttrStyle::Outer,
+            bracket_token: bracketed!(content in input),
+            path: if content.peek(Token![override]) || content.peek(Token![enum]) {
+                Path::from(content.call(Ident::parse_any)?)
+            } else {
+                content.call(Path::parse_mod_style)?
+            },
+            tokens: content.parse()?,
+        });
+    }
+    Ok(attrs)
+}
+
 pub fn join_path(path: &Path) -> String {
     path.segments
         .iter()
@@ -105,6 +544,23 @@ pub struct Declaration {
     pub args: Option<DeclarationArgs>,
 }
 
+impl Declaration {
+    // names of other properties listed in `notifies = [...]`
+    pub fn notifies(&self) -> Vec<LitStr> {
+        self.args
+            .iter()
+            .flat_map(|args| args.args.iter())
+            .filter_map(|arg| match arg {
+                DeclarationArg::KeyList(key, items) if join_path(key) == "notifies" => {
+                    Some(items.iter().cloned())
+                }
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+}
+
 pub struct DeclarationArgs {
     pub paren: token::Paren,
     pub args: Punctuated<DeclarationArg, Token![,]>,
@@ -126,21 +582,92 @@ pub enum DeclarationArg {
     Tag(Path),
     // key = value flags which compile to `.#key(#value)` calls on the builder
     KeyVal(Path, Token![=], Lit),
+    // key = ["value", ...] lists, which are interpreted by the macro itself (e.g. `notifies`)
+    KeyList(Path, Punctuated<LitStr, Token![,]>),
 }
 
 impl Parse for DeclarationArg {
     fn parse(input: ParseStream) -> Result<Self> {
+        // the `enum` type tag of `#[property(enum, SomeEnum)]` is a keyword
+        if input.peek(Token![enum]) {
+            let keyword: Token![enum] = input.parse()?;
+            return Ok(DeclarationArg::Tag(Path::from(Ident::new(
+                "enum",
+                keyword.span,
+            ))));
+        }
         let key: Path = input.parse()?;
         if input.peek(Token![=]) {
             let eq: Token![=] = input.parse()?;
-            let value: Lit = input.parse()?;
-            Ok(DeclarationArg::KeyVal(key, eq, value))
+            if input.peek(token::Bracket) {
+                let content;
+                bracketed!(content in input);
+                let items = Punctuated::parse_terminated(&content)?;
+                Ok(DeclarationArg::KeyList(key, items))
+            } else {
+                let value: Lit = input.parse()?;
+                Ok(DeclarationArg::KeyVal(key, eq, value))
+            }
         } else {
             Ok(DeclarationArg::Tag(key))
         }
     }
 }
 
+// Shorthand for properties which are stored in a field of the struct, e.g. `cell(count)`.
+// The kind of storage determines how the getter and setter access the field.
+pub struct Storage {
+    pub ident: Ident,
+    pub kind: StorageKind,
+    pub _paren: token::Paren,
+    pub field: Ident,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum StorageKind {
+    // `Cell<T>`
+    Cell,
+    // `RefCell<T>`
+    RefCell,
+    // `glib::WeakRef<T>`
+    Weak,
+    // `OnceCell<T>`
+    Once,
+}
+
+impl StorageKind {
+    pub fn from_ident(ident: &Ident) -> Option<Self> {
+        match ident.to_string().as_str() {
+            "cell" => Some(StorageKind::Cell),
+            "refcell" => Some(StorageKind::RefCell),
+            "weak" => Some(StorageKind::Weak),
+            "once" => Some(StorageKind::Once),
+            _ => None,
+        }
+    }
+}
+
+impl Parse for Storage {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident: Ident = input.parse()?;
+        let kind = StorageKind::from_ident(&ident).ok_or_else(|| {
+            syn::Error::new(
+                ident.span(),
+                format!(
+                    "Unsupported storage `{ident}`, expected `cell`, `refcell`, `weak` or `once`"
+                ),
+            )
+        })?;
+        let content;
+        Ok(Storage {
+            ident,
+            kind,
+            _paren: parenthesized!(content in input),
+            field: content.parse()?,
+        })
+    }
+}
+
 // a "get" or "set" block for a single property
 pub struct Block {
     pub name: Ident,
@@ -149,8 +676,18 @@ pub struct Block {
 
 impl Parse for Block {
     fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        // `validate = path::to::function` is short for `validate { path::to::function(value) }`
+        if input.peek(Token![=]) {
+            let _: Token![=] = input.parse()?;
+            let function: Path = input.parse()?;
+            return Ok(Block {
+                name,
+                block: parse_quote!({ #function(value) }),
+            });
+        }
         Ok(Block {
-            name: input.parse()?,
+            name,
             block: input.parse()?,
         })
     }
@@ -0,0 +1,143 @@
+use std::path::PathBuf;
+use syn::LitStr;
+
+// The keys of a `.gschema.xml` file, e.g. `#![schema = "data/org.example.App.gschema.xml"]`,
+// which the keys of `settings(...)` properties are checked against at compile time.
+pub struct SchemaFile {
+    pub path: String,
+    keys: Vec<Key>,
+}
+
+struct Key {
+    name: String,
+    variant_type: String,
+}
+
+impl SchemaFile {
+    // The path is relative to the crate's manifest directory. Failures are reported at the option.
+    pub fn load(path: &LitStr) -> Option<SchemaFile> {
+        let mut full_path = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
+        full_path.push(path.value());
+        match std::fs::read_to_string(&full_path) {
+            Ok(xml) => Some(SchemaFile {
+                path: full_path.display().to_string(),
+                keys: keys(&xml),
+            }),
+            Err(err) => {
+                path.span()
+                    .unwrap()
+                    .error(format!(
+                        "Cannot read the settings schema {}: {}",
+                        full_path.display(),
+                        err
+                    ))
+                    .emit();
+                None
+            }
+        }
+    }
+
+    // The GVariant types of the key, in all schemas of the file which declare it
+    pub fn key_types(&self, name: &str) -> Vec<&str> {
+        self.keys
+            .iter()
+            .filter(|key| key.name == name)
+            .map(|key| key.variant_type.as_str())
+            .collect()
+    }
+}
+
+// `<key name="..." type="..."/>` elements. Keys with `enum="..."` are strings, keys with `flags="..."` string arrays.
+fn keys(xml: &str) -> Vec<Key> {
+    let mut keys = vec![];
+    let xml = without_comments(xml);
+    let mut rest = xml.as_str();
+    while let Some(start) = rest.find("<key") {
+        rest = &rest[start + "<key".len()..];
+        if !rest.starts_with(char::is_whitespace) {
+            continue;
+        }
+        let tag = &rest[..rest.find('>').unwrap_or(rest.len())];
+        let variant_type = attribute(tag, "type")
+            .or_else(|| attribute(tag, "enum").map(|_| "s".to_string()))
+            .or_else(|| attribute(tag, "flags").map(|_| "as".to_string()));
+        if let (Some(name), Some(variant_type)) = (attribute(tag, "name"), variant_type) {
+            keys.push(Key { name, variant_type });
+        }
+    }
+    keys
+}
+
+fn without_comments(xml: &str) -> String {
+    let mut text = String::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find("<!--") {
+        text.push_str(&rest[..start]);
+        rest = match rest[start..].find("-->") {
+            Some(end) => &rest[start + end + "-->".len()..],
+            None => "",
+        };
+    }
+    text.push_str(rest);
+    text
+}
+
+// The value of `name="value"` (or `name='value'`) within a tag
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let mut rest = tag;
+    while let Some(start) = rest.find(name) {
+        let preceded_by_space = rest[..start].ends_with(char::is_whitespace) || start == 0;
+        rest = &rest[start + name.len()..];
+        let value = rest.trim_start().strip_prefix('=').map(str::trim_start);
+        if let (true, Some(value)) = (preceded_by_space, value) {
+            let quote = value.chars().next()?;
+            if quote == '"' || quote == '\'' {
+                let value = &value[1..];
+                return value.find(quote).map(|end| value[..end].to_string());
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XML: &str = r#"
+        <schemalist>
+          <schema id="org.example.App" path="/org/example/App/">
+            <!-- <key name="old" type="b"/> -->
+            <key name="font-size" type="i"><default>12</default></key>
+            <key type='s' name='font-name'><default>'Sans'</default></key>
+            <key name="theme" enum="org.example.Theme"><default>'dark'</default></key>
+            <keybindings/>
+          </schema>
+          <schema id="org.example.Other">
+            <key name="font-size" type="d"><default>1.0</default></key>
+          </schema>
+        </schemalist>
+    "#;
+
+    fn file() -> SchemaFile {
+        SchemaFile {
+            path: String::new(),
+            keys: keys(XML),
+        }
+    }
+
+    #[test]
+    fn key_types() {
+        let file = file();
+        assert_eq!(file.key_types("font-size"), ["i", "d"]);
+        assert_eq!(file.key_types("font-name"), ["s"]);
+        assert_eq!(file.key_types("theme"), ["s"]);
+    }
+
+    #[test]
+    fn skips_comments_and_other_elements() {
+        let file = file();
+        assert!(file.key_types("old").is_empty());
+        assert_eq!(file.keys.len(), 4);
+    }
+}
@@ -0,0 +1,125 @@
+// Calls which reach the fallback arm of a `#![chain_to_parent]` block are only chained up
+// for properties installed by an ancestor, with the id the ancestor installed them with.
+// GObject dispatches properties to the class which installed them, so the fallback arm is
+// only reached when `ObjectImpl::property` / `set_property` are called directly.
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk_properties_macro::properties;
+use std::cell::{Cell, RefCell};
+
+mod imp {
+    use super::*;
+
+    #[derive(Default)]
+    pub struct Parent {
+        pub level: Cell<i32>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Parent {
+        const NAME: &'static str = "ChainToParentParent";
+        type Type = super::Parent;
+        type ParentType = glib::Object;
+    }
+
+    impl ObjectImpl for Parent {
+        properties! {
+            #[int]
+            "level" => cell(level),
+        }
+    }
+
+    #[derive(Default)]
+    pub struct Child {
+        pub name: RefCell<String>,
+        pub size: Cell<i32>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Child {
+        const NAME: &'static str = "ChainToParentChild";
+        type Type = super::Child;
+        type ParentType = super::Parent;
+    }
+
+    impl ObjectImpl for Child {
+        properties! {
+            #![chain_to_parent]
+
+            #[string]
+            "name" => refcell(name),
+            #[int]
+            "size" => cell(size),
+        }
+    }
+
+    #[derive(Default)]
+    pub struct Unrelated {
+        pub level: Cell<i32>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Unrelated {
+        const NAME: &'static str = "ChainToParentUnrelated";
+        type Type = super::Unrelated;
+        type ParentType = glib::Object;
+    }
+
+    impl ObjectImpl for Unrelated {
+        properties! {
+            #[int]
+            "level" => cell(level),
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct Parent(ObjectSubclass<imp::Parent>);
+}
+
+glib::wrapper! {
+    pub struct Child(ObjectSubclass<imp::Child>) @extends Parent;
+}
+
+glib::wrapper! {
+    pub struct Unrelated(ObjectSubclass<imp::Unrelated>);
+}
+
+unsafe impl IsSubclassable<imp::Child> for Parent {}
+
+#[test]
+fn chains_properties_of_ancestors() {
+    let child: Child = glib::Object::new(&[("level", &3)]).unwrap();
+    let pspec = child.find_property("level").unwrap();
+    let imp = imp::Child::from_instance(&child);
+    // 3 is not an id of the child, and not the id of "level" in the parent either
+    assert_eq!(imp.property(&child, 3, &pspec).get::<i32>().unwrap(), 3);
+    imp.set_property(&child, 3, &5.to_value(), &pspec);
+    assert_eq!(child.property::<i32>("level"), 5);
+    assert_eq!(imp.size.get(), 0);
+}
+
+#[test]
+fn gobject_dispatches_to_the_ancestor() {
+    let child: Child = glib::Object::new(&[("level", &3), ("size", &4)]).unwrap();
+    child.set_property("level", 5);
+    assert_eq!(child.property::<i32>("level"), 5);
+    assert_eq!(
+        imp::Parent::from_instance(child.upcast_ref()).level.get(),
+        5
+    );
+    assert_eq!(child.property::<i32>("size"), 4);
+}
+
+#[test]
+fn does_not_chain_properties_of_other_classes() {
+    let child: Child = glib::Object::new(&[("level", &3)]).unwrap();
+    let unrelated: Unrelated = glib::Object::new(&[("level", &7)]).unwrap();
+    let pspec = unrelated.find_property("level").unwrap();
+    let imp = imp::Child::from_instance(&child);
+    assert_eq!(imp.property(&child, 3, &pspec).get::<i32>().unwrap(), 0);
+    imp.set_property(&child, 3, &9.to_value(), &pspec);
+    assert_eq!(child.property::<i32>("level"), 3);
+    assert_eq!(unrelated.property::<i32>("level"), 7);
+}
@@ -0,0 +1,65 @@
+// Values outside of the range of a clamped property are clamped by the generated 'set',
+// for each of the numeric types.
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk_properties_macro::properties;
+use std::cell::Cell;
+
+mod imp {
+    use super::*;
+
+    #[derive(Default)]
+    pub struct Clamped {
+        pub count: Cell<i32>,
+        pub volume: Cell<f64>,
+        pub offset: Cell<std::os::raw::c_long>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Clamped {
+        const NAME: &'static str = "ClampClamped";
+        type Type = super::Clamped;
+        type ParentType = glib::Object;
+    }
+
+    impl ObjectImpl for Clamped {
+        properties! {
+            #[int(clamp, minimum = 0, maximum = 100)]
+            "count" => cell(count),
+            #[double(clamp, minimum = 0.0, maximum = 1.0)]
+            "volume" => cell(volume),
+            #[long(clamp, minimum = -10, maximum = 10)]
+            "offset" => {
+                get { glib::ILong(self.offset.get()).to_value() }
+                set { self.offset.set(value.get::<glib::ILong>().unwrap().0) }
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct Clamped(ObjectSubclass<imp::Clamped>);
+}
+
+#[test]
+fn clamps_values() {
+    let clamped: Clamped = glib::Object::new(&[]).unwrap();
+    clamped.set_property("count", 250);
+    assert_eq!(clamped.property::<i32>("count"), 100);
+    clamped.set_property("volume", -0.5);
+    assert_eq!(clamped.property::<f64>("volume"), 0.0);
+    clamped.set_property("offset", glib::ILong(-20));
+    assert_eq!(clamped.property::<glib::ILong>("offset"), glib::ILong(-10));
+    clamped.set_property("offset", glib::ILong(20));
+    assert_eq!(clamped.property::<glib::ILong>("offset"), glib::ILong(10));
+}
+
+#[test]
+fn keeps_values_within_range() {
+    let clamped: Clamped = glib::Object::new(&[]).unwrap();
+    clamped.set_property("count", 42);
+    assert_eq!(clamped.property::<i32>("count"), 42);
+    clamped.set_property("offset", glib::ILong(3));
+    assert_eq!(clamped.property::<glib::ILong>("offset"), glib::ILong(3));
+}
@@ -0,0 +1,206 @@
+use gtk_properties_macro::properties;
+struct MyObject {
+    zoom: Cell<f64>,
+    title: RefCell<String>,
+}
+impl ObjectImpl for MyObject {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `zoom-level` | double | readwrite | 0.1 ..= 10.0 | 1.0 |  |
+    ///| `zoom` | double | readwrite, deprecated | 0.1 ..= 10.0 | 1.0 | Deprecated, use "zoom-level" instead |
+    ///| `title` | string | readwrite |  |  |  |
+    ///| `label` | string | readwrite |  |  | Alias of "title" |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecDouble::builder("zoom-level")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .minimum(0.1)
+                            .maximum(10.0)
+                            .default_value(1.0)
+                            .build(),
+                        ParamSpecDouble::builder("zoom")
+                            .flags(
+                                gtk::glib::ParamFlags::READWRITE
+                                    | gtk::glib::ParamFlags::DEPRECATED,
+                            )
+                            .blurb("Deprecated, use \"zoom-level\" instead")
+                            .minimum(0.1)
+                            .maximum(10.0)
+                            .default_value(1.0)
+                            .build(),
+                        ParamSpecString::builder("title")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                        ParamSpecString::builder("label")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .blurb("Alias of \"title\"")
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize | 2usize => self.zoom.get().to_value(),
+            3usize | 4usize => self.title.borrow().to_value(),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize | 2usize => {
+                {
+                    self.zoom.set(value.get().unwrap());
+                };
+                object.notify("zoom-level");
+                object.notify("zoom");
+            }
+            3usize | 4usize => {
+                {
+                    match value.get::<Option<String>>().unwrap() {
+                        Some(new_value) => {
+                            self.title.replace(new_value);
+                        }
+                        None => {
+                            {
+                                {
+                                    let log_domain = <Option<
+                                        &str,
+                                    > as std::convert::From<_>>::from("GLib-GObject");
+                                    {
+                                        let mut w = ::glib::GStringBuilder::default();
+                                        if !std::fmt::Write::write_fmt(
+                                                &mut w,
+                                                format_args!(
+                                                    "property \"{0}\" of type \'{1}\' is not nullable, and cannot be set to None",
+                                                    pspec.name(),
+                                                    object.type_(),
+                                                ),
+                                            )
+                                            .is_err()
+                                        {
+                                            unsafe {
+                                                ::glib::ffi::g_log(
+                                                    ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                                    <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                        ::glib::LogLevel::Warning,
+                                                    ),
+                                                    b"%s\0".as_ptr() as *const _,
+                                                    ::glib::translate::ToGlibPtr::<
+                                                        *const std::os::raw::c_char,
+                                                    >::to_glib_none(&w.into_string())
+                                                        .0,
+                                                );
+                                            }
+                                        }
+                                    };
+                                };
+                            };
+                            return;
+                        }
+                    }
+                };
+                object.notify("title");
+                object.notify("label");
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use gtk_properties_macro::properties;
+
+struct MyObject {
+    zoom: Cell<f64>,
+    title: RefCell<String>,
+}
+
+impl ObjectImpl for MyObject {
+    properties! {
+        #[double(minimum = 0.1, maximum = 10.0, default_value = 1.0)]
+        "zoom-level" alias "zoom" (deprecated) => {
+            get { self.zoom.get().to_value() }
+            set { self.zoom.set(value.get().unwrap()); }
+        }
+        #[string]
+        "title" alias "label" => refcell(title),
+    }
+}
@@ -0,0 +1,192 @@
+use gtk_properties_macro::properties;
+struct MyObject {
+    name: RefCell<String>,
+    count: Cell<i32>,
+    mode: Cell<i32>,
+}
+impl ObjectImpl for MyObject {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `name` | string | readwrite |  |  |  |
+    ///| `count` | int | readable |  |  |  |
+    ///| `mode` | int | readwrite |  |  |  |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecString::builder("name")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                        ParamSpecInt::builder("count")
+                            .flags(gtk::glib::ParamFlags::READABLE)
+                            .build(),
+                        ParamSpecInt::builder("mode")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        const PROPERTY_ID_3: usize = 2usize + false as usize;
+        const PROPERTY_ID_5: usize = 2usize + false as usize + true as usize
+            + false as usize;
+        match id {
+            1usize => self.name.borrow().to_value(),
+            PROPERTY_ID_3 => self.count.get().to_value(),
+            PROPERTY_ID_5 => self.mode.get().to_value(),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        const PROPERTY_ID_5: usize = 2usize + false as usize + true as usize
+            + false as usize;
+        match id {
+            1usize => {
+                {
+                    match value.get::<Option<String>>().unwrap() {
+                        Some(new_value) => {
+                            self.name.replace(new_value);
+                        }
+                        None => {
+                            {
+                                {
+                                    let log_domain = <Option<
+                                        &str,
+                                    > as std::convert::From<_>>::from("GLib-GObject");
+                                    {
+                                        let mut w = ::glib::GStringBuilder::default();
+                                        if !std::fmt::Write::write_fmt(
+                                                &mut w,
+                                                format_args!(
+                                                    "property \"{0}\" of type \'{1}\' is not nullable, and cannot be set to None",
+                                                    pspec.name(),
+                                                    object.type_(),
+                                                ),
+                                            )
+                                            .is_err()
+                                        {
+                                            unsafe {
+                                                ::glib::ffi::g_log(
+                                                    ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                                    <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                        ::glib::LogLevel::Warning,
+                                                    ),
+                                                    b"%s\0".as_ptr() as *const _,
+                                                    ::glib::translate::ToGlibPtr::<
+                                                        *const std::os::raw::c_char,
+                                                    >::to_glib_none(&w.into_string())
+                                                        .0,
+                                                );
+                                            }
+                                        }
+                                    };
+                                };
+                            };
+                            return;
+                        }
+                    }
+                };
+            }
+            PROPERTY_ID_5 => {
+                self.mode.set(value.get().unwrap());
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+}
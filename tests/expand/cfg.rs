@@ -0,0 +1,41 @@
+use gtk_properties_macro::properties;
+
+struct MyObject {
+    name: RefCell<String>,
+    #[cfg(feature = "extra")]
+    extra: Cell<i32>,
+    count: Cell<i32>,
+    mode: Cell<i32>,
+    #[cfg(feature = "fast")]
+    speed: Cell<f64>,
+    #[cfg(feature = "slow")]
+    delay: Cell<f64>,
+}
+
+impl ObjectImpl for MyObject {
+    properties! {
+        #[string(notifies = ["extra"])]
+        "name" => refcell(name),
+        #[cfg(feature = "extra")]
+        #[int]
+        "extra" => cell(extra),
+        #[cfg_attr(not(unix), cfg(feature = "extra"))]
+        #[int(readable)]
+        "count" => cell(count),
+        #[cfg(feature = "extra")]
+        #[int]
+        "total" => computed(name, count) { self.count.get() + self.extra.get() },
+        #[cfg(unix)]
+        #[int]
+        "mode" => cell(mode),
+        #[cfg(not(unix))]
+        #[int(readable)]
+        "mode" => cell(mode),
+        #[cfg(feature = "fast")]
+        #[double]
+        "rate" => cell(speed),
+        #[cfg(feature = "slow")]
+        #[double]
+        "rate" => cell(delay),
+    }
+}
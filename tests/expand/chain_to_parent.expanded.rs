@@ -0,0 +1,173 @@
+use gtk_properties_macro::properties;
+struct MyObject {
+    name: RefCell<String>,
+}
+impl ObjectImpl for MyObject {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `name` | string | readwrite |  |  |  |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecString::builder("name")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => self.name.borrow().clone().to_value(),
+            _ if {
+                let type_ = <Self as gtk::glib::subclass::types::ObjectSubclassType>::type_();
+                pspec.owner_type() != type_ && type_.is_a(pspec.owner_type())
+            } => {
+                unsafe {
+                    use gtk::glib::translate::*;
+                    let parent_class = &*(<Self as gtk::glib::subclass::types::ObjectSubclassType>::type_data()
+                        .as_ref()
+                        .parent_class() as *const gtk::glib::gobject_ffi::GObjectClass);
+                    let mut value = gtk::glib::Value::from_type(pspec.value_type());
+                    if let Some(f) = parent_class.get_property {
+                        let pspec: *mut gtk::glib::gobject_ffi::GParamSpec = pspec
+                            .to_glib_none()
+                            .0;
+                        f(
+                            object.as_ptr() as *mut gtk::glib::gobject_ffi::GObject,
+                            (*pspec).param_id,
+                            value.to_glib_none_mut().0,
+                            pspec,
+                        );
+                    }
+                    value
+                }
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => self.name.replace(value.get().unwrap()),
+            _ if {
+                let type_ = <Self as gtk::glib::subclass::types::ObjectSubclassType>::type_();
+                pspec.owner_type() != type_ && type_.is_a(pspec.owner_type())
+            } => {
+                unsafe {
+                    use gtk::glib::translate::*;
+                    let parent_class = &*(<Self as gtk::glib::subclass::types::ObjectSubclassType>::type_data()
+                        .as_ref()
+                        .parent_class() as *const gtk::glib::gobject_ffi::GObjectClass);
+                    if let Some(f) = parent_class.set_property {
+                        let pspec: *mut gtk::glib::gobject_ffi::GParamSpec = pspec
+                            .to_glib_none()
+                            .0;
+                        f(
+                            object.as_ptr() as *mut gtk::glib::gobject_ffi::GObject,
+                            (*pspec).param_id,
+                            value.to_glib_none().0 as *mut _,
+                            pspec,
+                        );
+                    }
+                }
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use gtk_properties_macro::properties;
+
+struct MyObject {
+    name: RefCell<String>,
+}
+
+impl ObjectImpl for MyObject {
+    properties! {
+        #![chain_to_parent]
+
+        #[string]
+        "name" => {
+            get { self.name.borrow().clone().to_value() }
+            set { self.name.replace(value.get().unwrap()) }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use gtk_properties_macro::properties;
+struct MyObject {
+    volume: Cell<f64>,
+    count: Cell<i32>,
+    offset: Cell<glib::ILong>,
+}
+impl ObjectImpl for MyObject {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `volume` | double | lax_validation, readwrite | 0.0 ..= 1.0 (clamped) |  |  |
+    ///| `count` | int | lax_validation, readwrite | 0 .. (clamped) |  |  |
+    ///| `offset` | long | lax_validation, readwrite | -10 ..= 10 (clamped) |  |  |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecDouble::builder("volume")
+                            .flags(
+                                gtk::glib::ParamFlags::LAX_VALIDATION
+                                    | gtk::glib::ParamFlags::READWRITE,
+                            )
+                            .minimum(0.0)
+                            .maximum(1.0)
+                            .build(),
+                        ParamSpecInt::builder("count")
+                            .flags(
+                                gtk::glib::ParamFlags::LAX_VALIDATION
+                                    | gtk::glib::ParamFlags::READWRITE,
+                            )
+                            .minimum(0)
+                            .build(),
+                        ParamSpecLong::builder("offset")
+                            .flags(
+                                gtk::glib::ParamFlags::LAX_VALIDATION
+                                    | gtk::glib::ParamFlags::READWRITE,
+                            )
+                            .minimum(-10)
+                            .maximum(10)
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => self.volume.get().to_value(),
+            2usize => self.count.get().to_value(),
+            3usize => self.offset.get().to_value(),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                let value = &{
+                    let new_value: f64 = value.get().unwrap();
+                    let new_value = new_value.max(0.0).min(1.0);
+                    new_value.to_value()
+                };
+                {
+                    self.volume.set(value.get().unwrap());
+                };
+            }
+            2usize => {
+                let value = &{
+                    let new_value: i32 = value.get().unwrap();
+                    let new_value = new_value.max(0);
+                    new_value.to_value()
+                };
+                { self.count.set(value.get().unwrap()) };
+            }
+            3usize => {
+                let value = &{
+                    let new_value: gtk::glib::ILong = value.get().unwrap();
+                    let new_value = new_value.0.max(-10).min(10);
+                    gtk::glib::ILong(new_value).to_value()
+                };
+                {
+                    self.offset.set(value.get().unwrap());
+                };
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use gtk_properties_macro::properties;
+
+struct MyObject {
+    volume: Cell<f64>,
+    count: Cell<i32>,
+    offset: Cell<glib::ILong>,
+}
+
+impl ObjectImpl for MyObject {
+    properties! {
+        #[double(clamp, minimum = 0.0, maximum = 1.0)]
+        "volume" => cell(volume),
+        #[int(clamp, minimum = 0)]
+        "count" => {
+            get { self.count.get().to_value() }
+            set { self.count.set(value.get().unwrap()) }
+        }
+        #[long(clamp, minimum = -10, maximum = 10)]
+        "offset" => cell(offset),
+    }
+}
@@ -0,0 +1,153 @@
+use gtk_properties_macro::properties;
+struct Rectangle {
+    width: Cell<f64>,
+    height: Cell<f64>,
+}
+impl ObjectImpl for Rectangle {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `width` | double | readwrite |  |  |  |
+    ///| `height` | double | readwrite |  |  |  |
+    ///| `area` | double | readable |  |  |  |
+    ///| `area-percentage` | double | readable |  |  | area, as a percentage of the unit square |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecDouble::builder("width")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                        ParamSpecDouble::builder("height")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                        ParamSpecDouble::builder("area")
+                            .flags(gtk::glib::ParamFlags::READABLE)
+                            .build(),
+                        ParamSpecDouble::builder("area-percentage")
+                            .flags(gtk::glib::ParamFlags::READABLE)
+                            .blurb("area, as a percentage of the unit square")
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => self.width.get().to_value(),
+            2usize => self.height.get().to_value(),
+            3usize => ({ self.width.get() * self.height.get() }).to_value(),
+            4usize => ({ self.width.get() * self.height.get() * 100.0 }).to_value(),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                { self.width.set(value.get().unwrap()) };
+                object.notify("area");
+                object.notify("area-percentage");
+            }
+            2usize => {
+                { self.height.set(value.get().unwrap()) };
+                object.notify("area");
+                object.notify("area-percentage");
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use gtk_properties_macro::properties;
+
+struct Rectangle {
+    width: Cell<f64>,
+    height: Cell<f64>,
+}
+
+impl ObjectImpl for Rectangle {
+    properties! {
+        #[double]
+        "width" => {
+            get { self.width.get().to_value() }
+            set { self.width.set(value.get().unwrap()) }
+        }
+        #[double]
+        "height" => {
+            get { self.height.get().to_value() }
+            set { self.height.set(value.get().unwrap()) }
+        }
+        #[double]
+        "area" => computed(width, height) { self.width.get() * self.height.get() }
+        /// area, as a percentage of the unit square
+        #[double]
+        "area-percentage" => computed(area) { self.width.get() * self.height.get() * 100.0 }
+    }
+}
@@ -0,0 +1,317 @@
+use gtk_properties_macro::{derived_properties, properties, Properties};
+struct MyObject {
+    name: RefCell<String>,
+}
+impl ObjectImpl for MyObject {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `name` | string | readwrite |  |  |  |
+    fn properties() -> &'static [glib::ParamSpec] {
+        use glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecString::builder("name")
+                            .flags(glib::ParamFlags::READWRITE)
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &glib::ParamSpec,
+    ) -> glib::Value {
+        use glib::prelude::*;
+        match id {
+            1usize => self.name.borrow().to_value(),
+            _ => {
+                (/*ERROR*/);
+                glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &glib::Value,
+        pspec: &glib::ParamSpec,
+    ) {
+        use glib::prelude::*;
+        match id {
+            1usize => {
+                match value.get::<Option<String>>().unwrap() {
+                    Some(new_value) => {
+                        self.name.replace(new_value);
+                    }
+                    None => {
+                        (/*ERROR*/);
+                        return;
+                    }
+                }
+            }
+            _ => {
+                (/*ERROR*/);
+            }
+        }
+    }
+}
+#[properties(crate = glib)]
+struct Counter {
+    #[property(int)]
+    count: Cell<i32>,
+}
+#[automatically_derived]
+impl ::core::default::Default for Counter {
+    #[inline]
+    fn default() -> Counter {
+        Counter {
+            count: ::core::default::Default::default(),
+        }
+    }
+}
+impl Counter {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `count` | int | readwrite |  |  |  |
+    fn derived_properties() -> &'static [glib::ParamSpec] {
+        use glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecInt::builder("count")
+                            .flags(glib::ParamFlags::READWRITE)
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn derived_property(
+        &self,
+        object: &<Self as glib::subclass::types::ObjectSubclass>::Type,
+        id: usize,
+        pspec: &glib::ParamSpec,
+    ) -> glib::Value {
+        use glib::prelude::*;
+        match id {
+            1usize => self.count.get().to_value(),
+            _ => {
+                (/*ERROR*/);
+                glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn derived_set_property(
+        &self,
+        object: &<Self as glib::subclass::types::ObjectSubclass>::Type,
+        id: usize,
+        value: &glib::Value,
+        pspec: &glib::ParamSpec,
+    ) {
+        use glib::prelude::*;
+        match id {
+            1usize => {
+                self.count.set(value.get().unwrap());
+            }
+            _ => {
+                (/*ERROR*/);
+            }
+        }
+    }
+}
+impl ObjectImpl for Counter {
+    fn properties() -> &'static [glib::ParamSpec] {
+        Self::derived_properties()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &glib::ParamSpec,
+    ) -> glib::Value {
+        self.derived_property(object, id, pspec)
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &glib::Value,
+        pspec: &glib::ParamSpec,
+    ) {
+        self.derived_set_property(object, id, value, pspec)
+    }
+}
+struct Preferences {
+    settings: my_toolkit::gio::Settings,
+}
+impl ObjectImpl for Preferences {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `font-size` | int | explicit_notify, readwrite |  |  |  |
+    fn properties() -> &'static [my_toolkit::glib::ParamSpec] {
+        use my_toolkit::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecInt::builder("font-size")
+                            .flags(
+                                my_toolkit::glib::ParamFlags::EXPLICIT_NOTIFY
+                                    | my_toolkit::glib::ParamFlags::READWRITE,
+                            )
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &my_toolkit::glib::ParamSpec,
+    ) -> my_toolkit::glib::Value {
+        use my_toolkit::glib::prelude::*;
+        match id {
+            1usize => {
+                my_toolkit::gio::prelude::SettingsExt::int(&self.settings, "font-size")
+                    .to_value()
+            }
+            _ => {
+                (/*ERROR*/);
+                my_toolkit::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &my_toolkit::glib::Value,
+        pspec: &my_toolkit::glib::ParamSpec,
+    ) {
+        use my_toolkit::glib::prelude::*;
+        match id {
+            1usize => {
+                let result = my_toolkit::gio::prelude::SettingsExt::set_int(
+                    &self.settings,
+                    "font-size",
+                    value.get().unwrap(),
+                );
+                if let Err(err) = result {
+                    (/*ERROR*/);
+                }
+            }
+            _ => {
+                (/*ERROR*/);
+            }
+        }
+    }
+    fn constructed(&self, object: &Self::Type) {
+        use my_toolkit::glib::prelude::*;
+        my_toolkit::glib::subclass::object::ObjectImplExt::parent_constructed(
+            self,
+            object,
+        );
+        {
+            let settings = &self.settings;
+            if let Some(schema) = my_toolkit::gio::prelude::SettingsExt::settings_schema(
+                settings,
+            ) {
+                if !schema.has_key("font-size") {
+                    (/*ERROR*/);
+                } else if schema.key("font-size").value_type().as_str() != "i" {
+                    (/*ERROR*/);
+                }
+            }
+            let object_weak = object.downgrade();
+            my_toolkit::gio::prelude::SettingsExt::connect_changed(
+                settings,
+                Some("font-size"),
+                move |_, _| {
+                    if let Some(object) = object_weak.upgrade() {
+                        object.notify("font-size");
+                    }
+                },
+            );
+        }
+    }
+}
+struct Label {
+    text: RefCell<String>,
+}
+impl ObjectImpl for Label {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `text` | string | readwrite |  |  |  |
+    fn properties() -> &'static [glib::ParamSpec] {
+        use glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecString::builder("text")
+                            .flags(glib::ParamFlags::READWRITE)
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+        use glib::prelude::*;
+        let object = glib::subclass::prelude::ObjectSubclassExt::obj(self);
+        let object: &Self::Type = &object;
+        match id {
+            1usize => self.text.borrow().to_value(),
+            _ => {
+                (/*ERROR*/);
+                glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+        use glib::prelude::*;
+        let object = glib::subclass::prelude::ObjectSubclassExt::obj(self);
+        let object: &Self::Type = &object;
+        match id {
+            1usize => {
+                match value.get::<Option<String>>().unwrap() {
+                    Some(new_value) => {
+                        self.text.replace(new_value);
+                    }
+                    None => {
+                        (/*ERROR*/);
+                        return;
+                    }
+                }
+            }
+            _ => {
+                (/*ERROR*/);
+            }
+        }
+    }
+}
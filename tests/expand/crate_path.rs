@@ -0,0 +1,52 @@
+use gtk_properties_macro::{derived_properties, properties, Properties};
+
+struct MyObject {
+    name: RefCell<String>,
+}
+
+impl ObjectImpl for MyObject {
+    properties! {
+        #![crate = glib]
+
+        #[string]
+        "name" => refcell(name),
+    }
+}
+
+#[derive(Default, Properties)]
+#[properties(crate = glib)]
+struct Counter {
+    #[property(int)]
+    count: Cell<i32>,
+}
+
+impl ObjectImpl for Counter {
+    derived_properties!(crate = glib);
+}
+
+struct Preferences {
+    settings: my_toolkit::gio::Settings,
+}
+
+impl ObjectImpl for Preferences {
+    properties! {
+        #![crate = my_toolkit::glib]
+
+        #[int]
+        "font-size" => settings(self.settings, "font-size"),
+    }
+}
+
+struct Label {
+    text: RefCell<String>,
+}
+
+impl ObjectImpl for Label {
+    properties! {
+        #![crate = glib]
+        #![glib_0_16]
+
+        #[string]
+        "text" => refcell(text),
+    }
+}
@@ -0,0 +1,166 @@
+use gtk_properties_macro::properties;
+struct MyObject {
+    count: Cell<i32>,
+    name: RefCell<String>,
+    mode: Cell<Mode>,
+}
+#[automatically_derived]
+impl ::core::default::Default for MyObject {
+    #[inline]
+    fn default() -> MyObject {
+        MyObject {
+            count: ::core::default::Default::default(),
+            name: ::core::default::Default::default(),
+            mode: ::core::default::Default::default(),
+        }
+    }
+}
+impl ObjectImpl for MyObject {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `count` | int | readwrite | 0 .. | `Self::default().count` |  |
+    ///| `name` | string | readable |  | `Self::default().name` |  |
+    ///| `mode` | enum(Mode) | readwrite |  | `Self::default().mode` |  |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                let defaults = Self::default();
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecInt::builder("count")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .minimum(0)
+                            .default_value(defaults.count.get())
+                            .build(),
+                        ParamSpecString::builder("name")
+                            .flags(gtk::glib::ParamFlags::READABLE)
+                            .default_value(
+                                Option::<String>::from(defaults.name.take()).as_deref(),
+                            )
+                            .build(),
+                        ParamSpecEnum::builder("mode", Mode::static_type())
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .default_value(
+                                gtk::glib::translate::IntoGlib::into_glib(
+                                    defaults.mode.get(),
+                                ),
+                            )
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => self.count.get().to_value(),
+            2usize => self.name.borrow().to_value(),
+            3usize => self.mode.get().to_value(),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                self.count.set(value.get().unwrap());
+            }
+            3usize => {
+                self.mode.set(value.get().unwrap());
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use gtk_properties_macro::properties;
+
+#[derive(Default)]
+struct MyObject {
+    count: Cell<i32>,
+    name: RefCell<String>,
+    mode: Cell<Mode>,
+}
+
+impl ObjectImpl for MyObject {
+    properties! {
+        #![default_from_struct]
+        #[int(minimum = 0)]
+        "count" => cell(count),
+        #[string(readable)]
+        "name" => refcell(name),
+        #[enum(Mode)]
+        "mode" => cell(mode),
+    }
+}
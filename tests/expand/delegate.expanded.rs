@@ -0,0 +1,302 @@
+use gtk_properties_macro::properties;
+struct MyWidget {
+    label: TemplateChild<gtk::Label>,
+    button: gtk::Button,
+}
+impl ObjectImpl for MyWidget {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `label` | string | readwrite |  | from `label` of `label` | blurb of `label` of `label` |
+    ///| `wrap` | boolean | readable |  | from `wrap` of `label` | Whether the label wraps |
+    ///| `button-label` | string | readwrite |  | from `label` of `button` | blurb of `label` of `button` |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        {
+                            struct Child<T>(std::marker::PhantomData<T>);
+                            trait ObjectChild {
+                                fn child_type(&self) -> Type;
+                            }
+                            impl<T: StaticType> ObjectChild for Child<T> {
+                                fn child_type(&self) -> Type {
+                                    T::static_type()
+                                }
+                            }
+                            trait DerefChild {
+                                fn child_type(&self) -> Type;
+                            }
+                            impl<T: std::ops::Deref> DerefChild for &Child<T>
+                            where
+                                T::Target: StaticType,
+                            {
+                                fn child_type(&self) -> Type {
+                                    T::Target::static_type()
+                                }
+                            }
+                            fn child<S, T>(_: fn(&S) -> &T) -> Child<T> {
+                                Child(std::marker::PhantomData)
+                            }
+                            let child_class = object::ObjectClass::from_type(
+                                    (&child(|this: &Self| &this.label)).child_type(),
+                                )
+                                .unwrap();
+                            let child_pspec = child_class
+                                .find_property("label")
+                                .unwrap_or_else(|| {
+                                    ::core::panicking::panic_fmt(
+                                        format_args!(
+                                            "Property \"label\" delegates to \"label\" of `label`, but \'{0}\' does not have such a property",
+                                            child_class.type_(),
+                                        ),
+                                    );
+                                });
+                            let child_pspec = child_pspec
+                                .downcast_ref::<ParamSpecString>()
+                                .unwrap_or_else(|| {
+                                    ::core::panicking::panic_fmt(
+                                        format_args!(
+                                            "Property \"label\" is declared as \'string\', but \"label\" of `label` is a {0}",
+                                            child_pspec.type_(),
+                                        ),
+                                    );
+                                });
+                            let param_spec = ParamSpecString::builder("label")
+                                .nick(child_pspec.nick())
+                                .flags(gtk::glib::ParamFlags::READWRITE)
+                                .blurb(child_pspec.blurb())
+                                .default_value(child_pspec.default_value())
+                                .build();
+                            param_spec
+                        },
+                        {
+                            struct Child<T>(std::marker::PhantomData<T>);
+                            trait ObjectChild {
+                                fn child_type(&self) -> Type;
+                            }
+                            impl<T: StaticType> ObjectChild for Child<T> {
+                                fn child_type(&self) -> Type {
+                                    T::static_type()
+                                }
+                            }
+                            trait DerefChild {
+                                fn child_type(&self) -> Type;
+                            }
+                            impl<T: std::ops::Deref> DerefChild for &Child<T>
+                            where
+                                T::Target: StaticType,
+                            {
+                                fn child_type(&self) -> Type {
+                                    T::Target::static_type()
+                                }
+                            }
+                            fn child<S, T>(_: fn(&S) -> &T) -> Child<T> {
+                                Child(std::marker::PhantomData)
+                            }
+                            let child_class = object::ObjectClass::from_type(
+                                    (&child(|this: &Self| &this.label)).child_type(),
+                                )
+                                .unwrap();
+                            let child_pspec = child_class
+                                .find_property("wrap")
+                                .unwrap_or_else(|| {
+                                    ::core::panicking::panic_fmt(
+                                        format_args!(
+                                            "Property \"wrap\" delegates to \"wrap\" of `label`, but \'{0}\' does not have such a property",
+                                            child_class.type_(),
+                                        ),
+                                    );
+                                });
+                            let child_pspec = child_pspec
+                                .downcast_ref::<ParamSpecBoolean>()
+                                .unwrap_or_else(|| {
+                                    ::core::panicking::panic_fmt(
+                                        format_args!(
+                                            "Property \"wrap\" is declared as \'boolean\', but \"wrap\" of `label` is a {0}",
+                                            child_pspec.type_(),
+                                        ),
+                                    );
+                                });
+                            let param_spec = ParamSpecBoolean::builder("wrap")
+                                .nick(child_pspec.nick())
+                                .flags(gtk::glib::ParamFlags::READABLE)
+                                .blurb("Whether the label wraps")
+                                .default_value(child_pspec.default_value())
+                                .build();
+                            param_spec
+                        },
+                        {
+                            struct Child<T>(std::marker::PhantomData<T>);
+                            trait ObjectChild {
+                                fn child_type(&self) -> Type;
+                            }
+                            impl<T: StaticType> ObjectChild for Child<T> {
+                                fn child_type(&self) -> Type {
+                                    T::static_type()
+                                }
+                            }
+                            trait DerefChild {
+                                fn child_type(&self) -> Type;
+                            }
+                            impl<T: std::ops::Deref> DerefChild for &Child<T>
+                            where
+                                T::Target: StaticType,
+                            {
+                                fn child_type(&self) -> Type {
+                                    T::Target::static_type()
+                                }
+                            }
+                            fn child<S, T>(_: fn(&S) -> &T) -> Child<T> {
+                                Child(std::marker::PhantomData)
+                            }
+                            let child_class = object::ObjectClass::from_type(
+                                    (&child(|this: &Self| &this.button)).child_type(),
+                                )
+                                .unwrap();
+                            let child_pspec = child_class
+                                .find_property("label")
+                                .unwrap_or_else(|| {
+                                    ::core::panicking::panic_fmt(
+                                        format_args!(
+                                            "Property \"button-label\" delegates to \"label\" of `button`, but \'{0}\' does not have such a property",
+                                            child_class.type_(),
+                                        ),
+                                    );
+                                });
+                            let child_pspec = child_pspec
+                                .downcast_ref::<ParamSpecString>()
+                                .unwrap_or_else(|| {
+                                    ::core::panicking::panic_fmt(
+                                        format_args!(
+                                            "Property \"button-label\" is declared as \'string\', but \"label\" of `button` is a {0}",
+                                            child_pspec.type_(),
+                                        ),
+                                    );
+                                });
+                            let param_spec = ParamSpecString::builder("button-label")
+                                .nick(child_pspec.nick())
+                                .flags(gtk::glib::ParamFlags::READWRITE)
+                                .blurb(child_pspec.blurb())
+                                .default_value(child_pspec.default_value())
+                                .build();
+                            param_spec
+                        },
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => self.label.property_value("label"),
+            2usize => self.label.property_value("wrap"),
+            3usize => self.button.property_value("label"),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                self.label.set_property_from_value("label", value);
+            }
+            3usize => {
+                self.button.set_property_from_value("label", value);
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+}
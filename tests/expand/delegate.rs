@@ -0,0 +1,18 @@
+use gtk_properties_macro::properties;
+
+struct MyWidget {
+    label: TemplateChild<gtk::Label>,
+    button: gtk::Button,
+}
+
+impl ObjectImpl for MyWidget {
+    properties! {
+        #[string]
+        "label" => delegate(label, "label"),
+        /// Whether the label wraps
+        #[boolean(readable)]
+        "wrap" => delegate(label, "wrap"),
+        #[string]
+        "button-label" => delegate(button, "label"),
+    }
+}
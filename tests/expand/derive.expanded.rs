@@ -0,0 +1,302 @@
+use gtk_properties_macro::{derived_properties, Properties};
+struct Counter {
+    /// current count
+    #[property(int, construct, minimum = 0)]
+    count: Cell<i32>,
+    #[property(string, readable, name = "label")]
+    title: RefCell<String>,
+    #[property(double)]
+    step_size: Cell<f64>,
+    other: Cell<u8>,
+    #[property(enum, gtk::Orientation)]
+    orientation: OnceCell<gtk::Orientation>,
+    #[property(object, gtk::Widget)]
+    child: OnceCell<gtk::Widget>,
+}
+#[automatically_derived]
+impl ::core::default::Default for Counter {
+    #[inline]
+    fn default() -> Counter {
+        Counter {
+            count: ::core::default::Default::default(),
+            title: ::core::default::Default::default(),
+            step_size: ::core::default::Default::default(),
+            other: ::core::default::Default::default(),
+            orientation: ::core::default::Default::default(),
+            child: ::core::default::Default::default(),
+        }
+    }
+}
+impl Counter {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `count` | int | construct, readwrite | 0 .. |  | current count |
+    ///| `label` | string | readable |  |  |  |
+    ///| `step-size` | double | readwrite |  |  |  |
+    ///| `orientation` | enum(gtk::Orientation) | construct_only, readwrite |  |  |  |
+    ///| `child` | object(gtk::Widget) | construct_only, readwrite |  |  |  |
+    fn derived_properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecInt::builder("count")
+                            .flags(
+                                gtk::glib::ParamFlags::CONSTRUCT
+                                    | gtk::glib::ParamFlags::READWRITE,
+                            )
+                            .blurb("current count")
+                            .minimum(0)
+                            .build(),
+                        ParamSpecString::builder("label")
+                            .flags(gtk::glib::ParamFlags::READABLE)
+                            .build(),
+                        ParamSpecDouble::builder("step-size")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                        ParamSpecEnum::builder(
+                                "orientation",
+                                gtk::Orientation::static_type(),
+                            )
+                            .flags(
+                                gtk::glib::ParamFlags::CONSTRUCT_ONLY
+                                    | gtk::glib::ParamFlags::READWRITE,
+                            )
+                            .build(),
+                        ParamSpecObject::builder("child", gtk::Widget::static_type())
+                            .flags(
+                                gtk::glib::ParamFlags::CONSTRUCT_ONLY
+                                    | gtk::glib::ParamFlags::READWRITE,
+                            )
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn derived_property(
+        &self,
+        object: &<Self as gtk::glib::subclass::types::ObjectSubclass>::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => self.count.get().to_value(),
+            2usize => self.title.borrow().to_value(),
+            3usize => self.step_size.get().to_value(),
+            4usize => {
+                match self.orientation.get() {
+                    Some(value) => value.to_value(),
+                    None => pspec.default_value().clone(),
+                }
+            }
+            5usize => {
+                match self.child.get() {
+                    Some(value) => value.to_value(),
+                    None => pspec.default_value().clone(),
+                }
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn derived_set_property(
+        &self,
+        object: &<Self as gtk::glib::subclass::types::ObjectSubclass>::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                self.count.set(value.get().unwrap());
+            }
+            3usize => {
+                self.step_size.set(value.get().unwrap());
+            }
+            4usize => {
+                let new_value = value.get().unwrap();
+                if self.orientation.set(new_value).is_err() {
+                    {
+                        {
+                            let log_domain = <Option<
+                                &str,
+                            > as std::convert::From<_>>::from("GLib-GObject");
+                            {
+                                let mut w = ::glib::GStringBuilder::default();
+                                if !std::fmt::Write::write_fmt(
+                                        &mut w,
+                                        format_args!(
+                                            "property \"{0}\" of type \'{1}\' can only be set once",
+                                            pspec.name(),
+                                            object.type_(),
+                                        ),
+                                    )
+                                    .is_err()
+                                {
+                                    unsafe {
+                                        ::glib::ffi::g_log(
+                                            ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                            <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                ::glib::LogLevel::Critical,
+                                            ),
+                                            b"%s\0".as_ptr() as *const _,
+                                            ::glib::translate::ToGlibPtr::<
+                                                *const std::os::raw::c_char,
+                                            >::to_glib_none(&w.into_string())
+                                                .0,
+                                        );
+                                    }
+                                }
+                            };
+                        };
+                    };
+                }
+            }
+            5usize => {
+                if let Some(new_value) = value.get::<Option<_>>().unwrap() {
+                    if self.child.set(new_value).is_err() {
+                        {
+                            {
+                                let log_domain = <Option<
+                                    &str,
+                                > as std::convert::From<_>>::from("GLib-GObject");
+                                {
+                                    let mut w = ::glib::GStringBuilder::default();
+                                    if !std::fmt::Write::write_fmt(
+                                            &mut w,
+                                            format_args!(
+                                                "property \"{0}\" of type \'{1}\' can only be set once",
+                                                pspec.name(),
+                                                object.type_(),
+                                            ),
+                                        )
+                                        .is_err()
+                                    {
+                                        unsafe {
+                                            ::glib::ffi::g_log(
+                                                ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                                <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                    ::glib::LogLevel::Critical,
+                                                ),
+                                                b"%s\0".as_ptr() as *const _,
+                                                ::glib::translate::ToGlibPtr::<
+                                                    *const std::os::raw::c_char,
+                                                >::to_glib_none(&w.into_string())
+                                                    .0,
+                                            );
+                                        }
+                                    }
+                                };
+                            };
+                        };
+                    }
+                }
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+}
+impl ObjectImpl for Counter {
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        Self::derived_properties()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        self.derived_property(object, id, pspec)
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        self.derived_set_property(object, id, value, pspec)
+    }
+}
@@ -0,0 +1,21 @@
+use gtk_properties_macro::{derived_properties, Properties};
+
+#[derive(Default, Properties)]
+struct Counter {
+    /// current count
+    #[property(int, construct, minimum = 0)]
+    count: Cell<i32>,
+    #[property(string, readable, name = "label")]
+    title: RefCell<String>,
+    #[property(double)]
+    step_size: Cell<f64>,
+    other: Cell<u8>,
+    #[property(enum, gtk::Orientation)]
+    orientation: OnceCell<gtk::Orientation>,
+    #[property(object, gtk::Widget)]
+    child: OnceCell<gtk::Widget>,
+}
+
+impl ObjectImpl for Counter {
+    derived_properties!();
+}
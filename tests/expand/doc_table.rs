@@ -0,0 +1,18 @@
+use gtk_properties_macro::{derived_properties, Properties};
+
+#[derive(Default, Properties)]
+#[properties(wrapper = super::Counter)]
+struct Counter {
+    /// Current count, see [`Counter::reset`](super::Counter::reset)
+    #[property(int, construct, minimum = 0, maximum = 100, default_value = 1, clamp)]
+    count: Cell<i32>,
+    /// Title | subtitle
+    #[property(string, readable, alias = "label")]
+    title: RefCell<String>,
+    #[property]
+    child: RefCell<Option<gtk::Widget>>,
+}
+
+impl ObjectImpl for Counter {
+    derived_properties!();
+}
@@ -1,24 +1,38 @@
 use gtk_properties_macro::properties;
 struct MyObject {
     name: RefCell<String>,
+    zoom: Cell<f64>,
 }
 impl ObjectImpl for MyObject {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `name` | string | readable |  |  | contains the name of this object |
+    ///| `zoom` | double | readwrite |  |  | How far the view is zoomed in, see ZoomLevel for common values. A zoom of 1.0 shows the original size. |
     fn properties() -> &'static [gtk::glib::ParamSpec] {
-        use once_cell::sync::Lazy;
         use gtk::glib::*;
-        static PROPERTIES: Lazy<Vec<ParamSpec>> = Lazy::new(|| {
-            <[_]>::into_vec(
-                #[rustc_box]
-                ::alloc::boxed::Box::new([
-                    ParamSpecString::builder("name")
-                        .flags(glib::ParamFlags::READABLE)
-                        .blurb("contains the name of this object")
-                        .nick("Object Name")
-                        .build(),
-                ]),
-            )
-        });
-        PROPERTIES.as_ref()
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecString::builder("name")
+                            .flags(gtk::glib::ParamFlags::READABLE)
+                            .blurb("contains the name of this object")
+                            .nick("Object Name")
+                            .build(),
+                        ParamSpecDouble::builder("zoom")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .blurb(
+                                "How far the view is zoomed in, see ZoomLevel for common values.\nA zoom of 1.0 shows the original size.",
+                            )
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
     }
     fn property(
         &self,
@@ -29,7 +43,46 @@ impl ObjectImpl for MyObject {
         use gtk::glib::prelude::*;
         match id {
             1usize => self.name.borrow().clone().to_value(),
-            _ => ::core::panicking::panic("not implemented"),
+            2usize => self.zoom.get().to_value(),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
         }
     }
     fn set_property(
@@ -41,7 +94,282 @@ impl ObjectImpl for MyObject {
     ) {
         use gtk::glib::prelude::*;
         match id {
-            _ => ::core::panicking::panic("not implemented"),
+            2usize => {
+                self.zoom.set(value.get().unwrap());
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+}
+struct OtherObject {
+    zoom: Cell<f64>,
+    label: RefCell<String>,
+}
+impl ObjectImpl for OtherObject {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `zoom` | double | readwrite |  |  | How far the view is zoomed in, relative to the original size. |
+    ///| `label` | string | readwrite |  |  | Label |
+    ///| `explicit` | string | readwrite |  |  | Ignored. Since there is an explicit nick. |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecDouble::builder("zoom")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .nick("Zoom level")
+                            .blurb(
+                                "How far the view is zoomed in, relative to the original size.",
+                            )
+                            .build(),
+                        ParamSpecString::builder("label")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .nick("Label")
+                            .blurb("Label")
+                            .build(),
+                        ParamSpecString::builder("explicit")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .blurb("Ignored. Since there is an explicit nick.")
+                            .nick("Explicit")
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => self.zoom.get().to_value(),
+            2usize => self.label.borrow().to_value(),
+            3usize => self.label.borrow().to_value(),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                self.zoom.set(value.get().unwrap());
+            }
+            2usize => {
+                match value.get::<Option<String>>().unwrap() {
+                    Some(new_value) => {
+                        self.label.replace(new_value);
+                    }
+                    None => {
+                        {
+                            {
+                                let log_domain = <Option<
+                                    &str,
+                                > as std::convert::From<_>>::from("GLib-GObject");
+                                {
+                                    let mut w = ::glib::GStringBuilder::default();
+                                    if !std::fmt::Write::write_fmt(
+                                            &mut w,
+                                            format_args!(
+                                                "property \"{0}\" of type \'{1}\' is not nullable, and cannot be set to None",
+                                                pspec.name(),
+                                                object.type_(),
+                                            ),
+                                        )
+                                        .is_err()
+                                    {
+                                        unsafe {
+                                            ::glib::ffi::g_log(
+                                                ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                                <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                    ::glib::LogLevel::Warning,
+                                                ),
+                                                b"%s\0".as_ptr() as *const _,
+                                                ::glib::translate::ToGlibPtr::<
+                                                    *const std::os::raw::c_char,
+                                                >::to_glib_none(&w.into_string())
+                                                    .0,
+                                            );
+                                        }
+                                    }
+                                };
+                            };
+                        };
+                        return;
+                    }
+                }
+            }
+            3usize => {
+                match value.get::<Option<String>>().unwrap() {
+                    Some(new_value) => {
+                        self.label.replace(new_value);
+                    }
+                    None => {
+                        {
+                            {
+                                let log_domain = <Option<
+                                    &str,
+                                > as std::convert::From<_>>::from("GLib-GObject");
+                                {
+                                    let mut w = ::glib::GStringBuilder::default();
+                                    if !std::fmt::Write::write_fmt(
+                                            &mut w,
+                                            format_args!(
+                                                "property \"{0}\" of type \'{1}\' is not nullable, and cannot be set to None",
+                                                pspec.name(),
+                                                object.type_(),
+                                            ),
+                                        )
+                                        .is_err()
+                                    {
+                                        unsafe {
+                                            ::glib::ffi::g_log(
+                                                ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                                <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                    ::glib::LogLevel::Warning,
+                                                ),
+                                                b"%s\0".as_ptr() as *const _,
+                                                ::glib::translate::ToGlibPtr::<
+                                                    *const std::os::raw::c_char,
+                                                >::to_glib_none(&w.into_string())
+                                                    .0,
+                                            );
+                                        }
+                                    }
+                                };
+                            };
+                        };
+                        return;
+                    }
+                }
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
         }
     }
 }
@@ -2,6 +2,7 @@ use gtk_properties_macro::properties;
 
 struct MyObject {
     name: RefCell<String>,
+    zoom: Cell<f64>,
 }
 
 impl ObjectImpl for MyObject {
@@ -11,5 +12,39 @@ impl ObjectImpl for MyObject {
         "name" => {
             get { self.name.borrow().clone().to_value() }
         }
+        /// How far the view is zoomed in, see
+        /// [`ZoomLevel`](crate::ZoomLevel) for **common** values.
+        ///
+        /// A `zoom` of 1.0 shows the [original] size.
+        ///
+        /// ```
+        /// object.set_property("zoom", 2.0);
+        /// ```
+        ///
+        /// [original]: https://example.com/original
+        #[double]
+        "zoom" => cell(zoom),
+    }
+}
+
+struct OtherObject {
+    zoom: Cell<f64>,
+    label: RefCell<String>,
+}
+
+impl ObjectImpl for OtherObject {
+    properties! {
+        #![nick_from_docs]
+
+        /// Zoom level. How far the view is zoomed in,
+        /// relative to the `original` size.
+        #[double]
+        "zoom" => cell(zoom),
+        /// Label
+        #[string]
+        "label" => refcell(label),
+        /// Ignored. Since there is an explicit nick.
+        #[string(nick = "Explicit")]
+        "explicit" => refcell(label),
     }
 }
@@ -3,23 +3,29 @@ struct MyObject {
     name: RefCell<String>,
 }
 impl ObjectImpl for MyObject {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `name` | string | readable, explicit_notify |  |  |  |
     fn properties() -> &'static [gtk::glib::ParamSpec] {
-        use once_cell::sync::Lazy;
         use gtk::glib::*;
-        static PROPERTIES: Lazy<Vec<ParamSpec>> = Lazy::new(|| {
-            <[_]>::into_vec(
-                #[rustc_box]
-                ::alloc::boxed::Box::new([
-                    ParamSpecString::builder("name")
-                        .flags(
-                            glib::ParamFlags::READABLE
-                                | glib::ParamFlags::EXPLICIT_NOTIFY,
-                        )
-                        .build(),
-                ]),
-            )
-        });
-        PROPERTIES.as_ref()
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecString::builder("name")
+                            .flags(
+                                gtk::glib::ParamFlags::READABLE
+                                    | gtk::glib::ParamFlags::EXPLICIT_NOTIFY,
+                            )
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
     }
     fn property(
         &self,
@@ -30,7 +36,45 @@ impl ObjectImpl for MyObject {
         use gtk::glib::prelude::*;
         match id {
             1usize => self.name.borrow().clone().to_value(),
-            _ => ::core::panicking::panic("not implemented"),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
         }
     }
     fn set_property(
@@ -42,7 +86,44 @@ impl ObjectImpl for MyObject {
     ) {
         use gtk::glib::prelude::*;
         match id {
-            _ => ::core::panicking::panic("not implemented"),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
         }
     }
 }
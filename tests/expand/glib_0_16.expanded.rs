@@ -0,0 +1,329 @@
+use gtk_properties_macro::{derived_properties, properties, Properties};
+struct MyObject {
+    name: RefCell<String>,
+    child: RefCell<Option<gtk::Button>>,
+    mode: Cell<Mode>,
+    fallback_mode: Cell<Mode>,
+}
+impl ObjectImpl for MyObject {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `name` | string | readwrite |  |  |  |
+    ///| `child` | object(gtk::Button) | readwrite |  |  |  |
+    ///| `mode` | enum(Mode) | readwrite |  | 1 |  |
+    ///| `fallback-mode` | enum(Mode) | readwrite |  |  |  |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecString::builder("name")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                        ParamSpecObject::builder::<gtk::Button>("child")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                        ParamSpecEnum::builder::<
+                            Mode,
+                        >(
+                                "mode",
+                                unsafe { gtk::glib::translate::FromGlib::from_glib(1) },
+                            )
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                        ParamSpecEnum::builder::<
+                            Mode,
+                        >(
+                                "fallback-mode",
+                                unsafe {
+                                    gtk::glib::translate::FromGlib::from_glib(
+                                        gtk::glib::EnumClass::new(Mode::static_type())
+                                            .unwrap()
+                                            .values()[0]
+                                            .value(),
+                                    )
+                                },
+                            )
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(&self, id: usize, pspec: &gtk::glib::ParamSpec) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        let object = gtk::glib::subclass::prelude::ObjectSubclassExt::obj(self);
+        let object: &Self::Type = &object;
+        match id {
+            1usize => self.name.borrow().to_value(),
+            2usize => self.child.borrow().to_value(),
+            3usize => self.mode.get().to_value(),
+            4usize => self.fallback_mode.get().to_value(),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        let object = gtk::glib::subclass::prelude::ObjectSubclassExt::obj(self);
+        let object: &Self::Type = &object;
+        match id {
+            1usize => {
+                self.name.replace(value.get().unwrap());
+                object.notify("name");
+            }
+            2usize => {
+                self.child.replace(value.get().unwrap());
+            }
+            3usize => {
+                self.mode.set(value.get().unwrap());
+            }
+            4usize => {
+                self.fallback_mode.set(value.get().unwrap());
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+}
+#[properties(glib_0_16)]
+struct Counter {
+    #[property(int)]
+    count: Cell<i32>,
+}
+#[automatically_derived]
+impl ::core::default::Default for Counter {
+    #[inline]
+    fn default() -> Counter {
+        Counter {
+            count: ::core::default::Default::default(),
+        }
+    }
+}
+impl Counter {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `count` | int | readwrite |  |  |  |
+    fn derived_properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecInt::builder("count")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn derived_property(
+        &self,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        let object = gtk::glib::subclass::prelude::ObjectSubclassExt::obj(self);
+        let object: &<Self as gtk::glib::subclass::types::ObjectSubclass>::Type = &object;
+        match id {
+            1usize => self.count.get().to_value(),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn derived_set_property(
+        &self,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        let object = gtk::glib::subclass::prelude::ObjectSubclassExt::obj(self);
+        let object: &<Self as gtk::glib::subclass::types::ObjectSubclass>::Type = &object;
+        match id {
+            1usize => {
+                self.count.set(value.get().unwrap());
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+}
+impl ObjectImpl for Counter {
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        Self::derived_properties()
+    }
+    fn property(&self, id: usize, pspec: &gtk::glib::ParamSpec) -> gtk::glib::Value {
+        self.derived_property(id, pspec)
+    }
+    fn set_property(
+        &self,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        self.derived_set_property(id, value, pspec)
+    }
+}
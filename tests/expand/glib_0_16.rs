@@ -0,0 +1,40 @@
+use gtk_properties_macro::{derived_properties, properties, Properties};
+
+struct MyObject {
+    name: RefCell<String>,
+    child: RefCell<Option<gtk::Button>>,
+    mode: Cell<Mode>,
+    fallback_mode: Cell<Mode>,
+}
+
+impl ObjectImpl for MyObject {
+    properties! {
+        #![glib_0_16]
+
+        #[string]
+        "name" => {
+            get { self.name.borrow().to_value() }
+            set {
+                self.name.replace(value.get().unwrap());
+                object.notify("name");
+            }
+        }
+        #[object(gtk::Button, nullable)]
+        "child" => refcell(child),
+        #[enum(Mode, default_value = 1)]
+        "mode" => cell(mode),
+        #[enum(Mode)]
+        "fallback-mode" => cell(fallback_mode),
+    }
+}
+
+#[derive(Default, Properties)]
+#[properties(glib_0_16)]
+struct Counter {
+    #[property(int)]
+    count: Cell<i32>,
+}
+
+impl ObjectImpl for Counter {
+    derived_properties!(glib_0_16);
+}
@@ -0,0 +1,188 @@
+use gtk_properties_macro::{properties, property_group};
+struct MyObject {
+    name: RefCell<String>,
+    x: Cell<i32>,
+    y: Cell<i32>,
+}
+impl ObjectImpl for MyObject {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `name` | string | readwrite |  |  |  |
+    ///| `x` | int | readwrite |  |  |  |
+    ///| `y` | int | readwrite |  |  |  |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecString::builder("name")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                        ParamSpecInt::builder("x")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                        ParamSpecInt::builder("y")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => self.name.borrow().to_value(),
+            2usize => self.x.get().to_value(),
+            3usize => self.y.get().to_value(),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                match value.get::<Option<String>>().unwrap() {
+                    Some(new_value) => {
+                        self.name.replace(new_value);
+                    }
+                    None => {
+                        {
+                            {
+                                let log_domain = <Option<
+                                    &str,
+                                > as std::convert::From<_>>::from("GLib-GObject");
+                                {
+                                    let mut w = ::glib::GStringBuilder::default();
+                                    if !std::fmt::Write::write_fmt(
+                                            &mut w,
+                                            format_args!(
+                                                "property \"{0}\" of type \'{1}\' is not nullable, and cannot be set to None",
+                                                pspec.name(),
+                                                object.type_(),
+                                            ),
+                                        )
+                                        .is_err()
+                                    {
+                                        unsafe {
+                                            ::glib::ffi::g_log(
+                                                ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                                <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                    ::glib::LogLevel::Warning,
+                                                ),
+                                                b"%s\0".as_ptr() as *const _,
+                                                ::glib::translate::ToGlibPtr::<
+                                                    *const std::os::raw::c_char,
+                                                >::to_glib_none(&w.into_string())
+                                                    .0,
+                                            );
+                                        }
+                                    }
+                                };
+                            };
+                        };
+                        return;
+                    }
+                }
+            }
+            2usize => {
+                self.x.set(value.get().unwrap());
+            }
+            3usize => {
+                self.y.set(value.get().unwrap());
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use gtk_properties_macro::{properties, property_group};
+
+property_group!(Geometry {
+    #[int]
+    "x" => cell(x),
+    #[int]
+    "y" => cell(y),
+});
+
+struct MyObject {
+    name: RefCell<String>,
+    x: Cell<i32>,
+    y: Cell<i32>,
+}
+
+impl ObjectImpl for MyObject {
+    properties! {
+        #[string]
+        "name" => refcell(name),
+        include Geometry;
+    }
+}
@@ -3,26 +3,34 @@ struct MyObject {
     name: RefCell<String>,
 }
 impl ObjectImpl for MyObject {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `implicitly-read-only` | string | readable |  |  |  |
+    ///| `implicitly-write-only` | string | writable |  |  |  |
+    ///| `implicitly-read-write` | string | readwrite |  |  |  |
     fn properties() -> &'static [gtk::glib::ParamSpec] {
-        use once_cell::sync::Lazy;
         use gtk::glib::*;
-        static PROPERTIES: Lazy<Vec<ParamSpec>> = Lazy::new(|| {
-            <[_]>::into_vec(
-                #[rustc_box]
-                ::alloc::boxed::Box::new([
-                    ParamSpecString::builder("implicitly-read-only")
-                        .flags(glib::ParamFlags::READABLE)
-                        .build(),
-                    ParamSpecString::builder("implicitly-write-only")
-                        .flags(glib::ParamFlags::WRITABLE)
-                        .build(),
-                    ParamSpecString::builder("implicitly-read-write")
-                        .flags(glib::ParamFlags::READWRITE)
-                        .build(),
-                ]),
-            )
-        });
-        PROPERTIES.as_ref()
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecString::builder("implicitly-read-only")
+                            .flags(gtk::glib::ParamFlags::READABLE)
+                            .build(),
+                        ParamSpecString::builder("implicitly-write-only")
+                            .flags(gtk::glib::ParamFlags::WRITABLE)
+                            .build(),
+                        ParamSpecString::builder("implicitly-read-write")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
     }
     fn property(
         &self,
@@ -34,7 +42,45 @@ impl ObjectImpl for MyObject {
         match id {
             1usize => self.name.borrow().clone().to_value(),
             3usize => self.name.borrow().clone().to_value(),
-            _ => ::core::panicking::panic("not implemented"),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
         }
     }
     fn set_property(
@@ -48,7 +94,44 @@ impl ObjectImpl for MyObject {
         match id {
             2usize => self.name.replace(value.get().unwrap()),
             3usize => self.name.replace(value.get().unwrap()),
-            _ => ::core::panicking::panic("not implemented"),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
         }
     }
 }
@@ -0,0 +1,299 @@
+use gtk_properties_macro::{derived_properties, Properties};
+struct Counter {
+    #[property(construct, minimum = 0)]
+    count: Cell<i32>,
+    #[property]
+    title: RefCell<String>,
+    #[property]
+    subtitle: RefCell<Option<String>>,
+    #[property(readable)]
+    ratio: Cell<f64>,
+    #[property]
+    child: RefCell<Option<gtk::Widget>>,
+    #[property]
+    mode: Cell<Mode>,
+}
+#[automatically_derived]
+impl ::core::default::Default for Counter {
+    #[inline]
+    fn default() -> Counter {
+        Counter {
+            count: ::core::default::Default::default(),
+            title: ::core::default::Default::default(),
+            subtitle: ::core::default::Default::default(),
+            ratio: ::core::default::Default::default(),
+            child: ::core::default::Default::default(),
+            mode: ::core::default::Default::default(),
+        }
+    }
+}
+impl Counter {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `count` | int | construct, readwrite | 0 .. |  |  |
+    ///| `title` | string | readwrite |  |  |  |
+    ///| `subtitle` | string | readwrite |  |  |  |
+    ///| `ratio` | double | readable |  |  |  |
+    ///| `child` | object(gtk::Widget) | readwrite |  |  |  |
+    ///| `mode` | enum(Mode) | readwrite |  |  |  |
+    fn derived_properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecInt::builder("count")
+                            .flags(
+                                gtk::glib::ParamFlags::CONSTRUCT
+                                    | gtk::glib::ParamFlags::READWRITE,
+                            )
+                            .minimum(0)
+                            .build(),
+                        ParamSpecString::builder("title")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                        ParamSpecString::builder("subtitle")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                        ParamSpecDouble::builder("ratio")
+                            .flags(gtk::glib::ParamFlags::READABLE)
+                            .build(),
+                        ParamSpecObject::builder("child", gtk::Widget::static_type())
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                        ParamSpecEnum::builder("mode", Mode::static_type())
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn derived_property(
+        &self,
+        object: &<Self as gtk::glib::subclass::types::ObjectSubclass>::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => self.count.get().to_value(),
+            2usize => self.title.borrow().to_value(),
+            3usize => self.subtitle.borrow().to_value(),
+            4usize => self.ratio.get().to_value(),
+            5usize => self.child.borrow().to_value(),
+            6usize => self.mode.get().to_value(),
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn derived_set_property(
+        &self,
+        object: &<Self as gtk::glib::subclass::types::ObjectSubclass>::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                self.count.set(value.get().unwrap());
+            }
+            2usize => {
+                match value.get::<Option<String>>().unwrap() {
+                    Some(new_value) => {
+                        self.title.replace(new_value);
+                    }
+                    None => {
+                        {
+                            {
+                                let log_domain = <Option<
+                                    &str,
+                                > as std::convert::From<_>>::from("GLib-GObject");
+                                {
+                                    let mut w = ::glib::GStringBuilder::default();
+                                    if !std::fmt::Write::write_fmt(
+                                            &mut w,
+                                            format_args!(
+                                                "property \"{0}\" of type \'{1}\' is not nullable, and cannot be set to None",
+                                                pspec.name(),
+                                                object.type_(),
+                                            ),
+                                        )
+                                        .is_err()
+                                    {
+                                        unsafe {
+                                            ::glib::ffi::g_log(
+                                                ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                                <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                    ::glib::LogLevel::Warning,
+                                                ),
+                                                b"%s\0".as_ptr() as *const _,
+                                                ::glib::translate::ToGlibPtr::<
+                                                    *const std::os::raw::c_char,
+                                                >::to_glib_none(&w.into_string())
+                                                    .0,
+                                            );
+                                        }
+                                    }
+                                };
+                            };
+                        };
+                        return;
+                    }
+                }
+            }
+            3usize => {
+                self.subtitle.replace(value.get().unwrap());
+            }
+            5usize => {
+                match value.get::<Option<_>>().unwrap() {
+                    Some(new_value) => {
+                        self.child.replace(Some(new_value));
+                    }
+                    None => {
+                        {
+                            {
+                                let log_domain = <Option<
+                                    &str,
+                                > as std::convert::From<_>>::from("GLib-GObject");
+                                {
+                                    let mut w = ::glib::GStringBuilder::default();
+                                    if !std::fmt::Write::write_fmt(
+                                            &mut w,
+                                            format_args!(
+                                                "property \"{0}\" of type \'{1}\' is not nullable, and cannot be set to None",
+                                                pspec.name(),
+                                                object.type_(),
+                                            ),
+                                        )
+                                        .is_err()
+                                    {
+                                        unsafe {
+                                            ::glib::ffi::g_log(
+                                                ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                                <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                    ::glib::LogLevel::Warning,
+                                                ),
+                                                b"%s\0".as_ptr() as *const _,
+                                                ::glib::translate::ToGlibPtr::<
+                                                    *const std::os::raw::c_char,
+                                                >::to_glib_none(&w.into_string())
+                                                    .0,
+                                            );
+                                        }
+                                    }
+                                };
+                            };
+                        };
+                        return;
+                    }
+                }
+            }
+            6usize => {
+                self.mode.set(value.get().unwrap());
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+}
+impl ObjectImpl for Counter {
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        Self::derived_properties()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        self.derived_property(object, id, pspec)
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        self.derived_set_property(object, id, value, pspec)
+    }
+}
@@ -0,0 +1,21 @@
+use gtk_properties_macro::{derived_properties, Properties};
+
+#[derive(Default, Properties)]
+struct Counter {
+    #[property(construct, minimum = 0)]
+    count: Cell<i32>,
+    #[property]
+    title: RefCell<String>,
+    #[property]
+    subtitle: RefCell<Option<String>>,
+    #[property(readable)]
+    ratio: Cell<f64>,
+    #[property]
+    child: RefCell<Option<gtk::Widget>>,
+    #[property]
+    mode: Cell<Mode>,
+}
+
+impl ObjectImpl for Counter {
+    derived_properties!();
+}
@@ -0,0 +1,31 @@
+use gtk_properties_macro::properties;
+unsafe impl ObjectInterface for Greeter {
+    const NAME: &'static str = "Greeter";
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `greeting` | string | readwrite |  |  | the greeting |
+    ///| `count` | int | readable | 0 .. |  |  |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecString::builder("greeting")
+                            .flags(gtk::glib::ParamFlags::READWRITE)
+                            .blurb("the greeting")
+                            .build(),
+                        ParamSpecInt::builder("count")
+                            .flags(gtk::glib::ParamFlags::READABLE)
+                            .minimum(0)
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+}
@@ -0,0 +1,15 @@
+use gtk_properties_macro::properties;
+
+unsafe impl ObjectInterface for Greeter {
+    const NAME: &'static str = "Greeter";
+
+    properties! {
+        #![interface]
+
+        /// the greeting
+        #[string]
+        "greeting";
+        #[int(readable, minimum = 0)]
+        "count";
+    }
+}
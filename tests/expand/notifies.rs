@@ -0,0 +1,25 @@
+use gtk_properties_macro::properties;
+
+struct Rectangle {
+    width: Cell<f64>,
+    height: Cell<f64>,
+}
+
+impl ObjectImpl for Rectangle {
+    properties! {
+        #[double(notifies = ["area"])]
+        "width" => {
+            get { self.width.get().to_value() }
+            set { self.width.set(value.get().unwrap()) }
+        }
+        #[double(notifies = ["area"])]
+        "height" => {
+            get { self.height.get().to_value() }
+            set { self.height.set(value.get().unwrap()) }
+        }
+        #[double]
+        "area" => {
+            get { (self.width.get() * self.height.get()).to_value() }
+        }
+    }
+}
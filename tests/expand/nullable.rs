@@ -0,0 +1,18 @@
+use gtk_properties_macro::properties;
+
+struct MyObject {
+    label: RefCell<String>,
+    tooltip: RefCell<Option<String>>,
+    child: RefCell<Option<gtk::Widget>>,
+}
+
+impl ObjectImpl for MyObject {
+    properties! {
+        #[string]
+        "label" => refcell(label),
+        #[string(nullable)]
+        "tooltip" => refcell(tooltip),
+        #[object(gtk::Widget)]
+        "child" => refcell(child),
+    }
+}
@@ -0,0 +1,230 @@
+use gtk_properties_macro::properties;
+struct MyObject {
+    id: OnceCell<i32>,
+    widget: OnceCell<gtk::Widget>,
+}
+impl ObjectImpl for MyObject {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `id` | int | construct_only, readwrite |  |  |  |
+    ///| `widget` | object(gtk::Widget) | construct_only, readwrite |  |  |  |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecInt::builder("id")
+                            .flags(
+                                gtk::glib::ParamFlags::CONSTRUCT_ONLY
+                                    | gtk::glib::ParamFlags::READWRITE,
+                            )
+                            .build(),
+                        ParamSpecObject::builder(
+                                "widget",
+                                gtk::Widget::static_type(),
+                            )
+                            .flags(
+                                gtk::glib::ParamFlags::CONSTRUCT_ONLY
+                                    | gtk::glib::ParamFlags::READWRITE,
+                            )
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                match self.id.get() {
+                    Some(value) => value.to_value(),
+                    None => pspec.default_value().clone(),
+                }
+            }
+            2usize => {
+                match self.widget.get() {
+                    Some(value) => value.to_value(),
+                    None => pspec.default_value().clone(),
+                }
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                let new_value = value.get().unwrap();
+                if self.id.set(new_value).is_err() {
+                    {
+                        {
+                            let log_domain = <Option<
+                                &str,
+                            > as std::convert::From<_>>::from("GLib-GObject");
+                            {
+                                let mut w = ::glib::GStringBuilder::default();
+                                if !std::fmt::Write::write_fmt(
+                                        &mut w,
+                                        format_args!(
+                                            "property \"{0}\" of type \'{1}\' can only be set once",
+                                            pspec.name(),
+                                            object.type_(),
+                                        ),
+                                    )
+                                    .is_err()
+                                {
+                                    unsafe {
+                                        ::glib::ffi::g_log(
+                                            ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                            <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                ::glib::LogLevel::Critical,
+                                            ),
+                                            b"%s\0".as_ptr() as *const _,
+                                            ::glib::translate::ToGlibPtr::<
+                                                *const std::os::raw::c_char,
+                                            >::to_glib_none(&w.into_string())
+                                                .0,
+                                        );
+                                    }
+                                }
+                            };
+                        };
+                    };
+                }
+            }
+            2usize => {
+                if let Some(new_value) = value.get::<Option<_>>().unwrap() {
+                    if self.widget.set(new_value).is_err() {
+                        {
+                            {
+                                let log_domain = <Option<
+                                    &str,
+                                > as std::convert::From<_>>::from("GLib-GObject");
+                                {
+                                    let mut w = ::glib::GStringBuilder::default();
+                                    if !std::fmt::Write::write_fmt(
+                                            &mut w,
+                                            format_args!(
+                                                "property \"{0}\" of type \'{1}\' can only be set once",
+                                                pspec.name(),
+                                                object.type_(),
+                                            ),
+                                        )
+                                        .is_err()
+                                    {
+                                        unsafe {
+                                            ::glib::ffi::g_log(
+                                                ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                                <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                    ::glib::LogLevel::Critical,
+                                                ),
+                                                b"%s\0".as_ptr() as *const _,
+                                                ::glib::translate::ToGlibPtr::<
+                                                    *const std::os::raw::c_char,
+                                                >::to_glib_none(&w.into_string())
+                                                    .0,
+                                            );
+                                        }
+                                    }
+                                };
+                            };
+                        };
+                    }
+                }
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use gtk_properties_macro::properties;
+
+struct MyObject {
+    id: OnceCell<i32>,
+    widget: OnceCell<gtk::Widget>,
+}
+
+impl ObjectImpl for MyObject {
+    properties! {
+        #[int]
+        "id" => once(id),
+        #[object(gtk::Widget)]
+        "widget" => once(widget),
+    }
+}
@@ -0,0 +1,19 @@
+use gtk_properties_macro::properties;
+
+struct MyBox {
+    orientation: Cell<gtk::Orientation>,
+}
+
+impl ObjectImpl for MyBox {
+    properties! {
+        #[override(gtk::Orientable)]
+        "orientation" => {
+            get { self.orientation.get().to_value() }
+            set { self.orientation.set(value.get().unwrap()) }
+        }
+        #[override(gtk::Widget, class)]
+        "tooltip-text" => {
+            get { None::<String>.to_value() }
+        }
+    }
+}
@@ -0,0 +1,934 @@
+use gtk_properties_macro::properties;
+struct Preferences {
+    settings: gio::Settings,
+}
+impl ObjectImpl for Preferences {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `font-size` | int | explicit_notify, readwrite |  |  |  |
+    ///| `font-name` | string | readable, explicit_notify |  |  |  |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecInt::builder("font-size")
+                            .flags(
+                                gtk::glib::ParamFlags::EXPLICIT_NOTIFY
+                                    | gtk::glib::ParamFlags::READWRITE,
+                            )
+                            .build(),
+                        ParamSpecString::builder("font-name")
+                            .flags(
+                                gtk::glib::ParamFlags::READABLE
+                                    | gtk::glib::ParamFlags::EXPLICIT_NOTIFY,
+                            )
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                gtk::gio::prelude::SettingsExt::int(&self.settings, "font-size")
+                    .to_value()
+            }
+            2usize => {
+                gtk::gio::prelude::SettingsExt::string(&self.settings, "font-name")
+                    .to_value()
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                let result = gtk::gio::prelude::SettingsExt::set_int(
+                    &self.settings,
+                    "font-size",
+                    value.get().unwrap(),
+                );
+                if let Err(err) = result {
+                    {
+                        {
+                            let log_domain = <Option<
+                                &str,
+                            > as std::convert::From<_>>::from("GLib-GObject");
+                            {
+                                let mut w = ::glib::GStringBuilder::default();
+                                if !std::fmt::Write::write_fmt(
+                                        &mut w,
+                                        format_args!(
+                                            "property \"{0}\" of type \'{1}\' cannot be stored in settings key \"{2}\": {3}",
+                                            pspec.name(),
+                                            object.type_(),
+                                            "font-size",
+                                            err,
+                                        ),
+                                    )
+                                    .is_err()
+                                {
+                                    unsafe {
+                                        ::glib::ffi::g_log(
+                                            ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                            <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                ::glib::LogLevel::Warning,
+                                            ),
+                                            b"%s\0".as_ptr() as *const _,
+                                            ::glib::translate::ToGlibPtr::<
+                                                *const std::os::raw::c_char,
+                                            >::to_glib_none(&w.into_string())
+                                                .0,
+                                        );
+                                    }
+                                }
+                            };
+                        };
+                    };
+                }
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+    fn constructed(&self, object: &Self::Type) {
+        use gtk::glib::prelude::*;
+        gtk::glib::subclass::object::ObjectImplExt::parent_constructed(self, object);
+        {
+            let settings = &self.settings;
+            if let Some(schema) = gtk::gio::prelude::SettingsExt::settings_schema(
+                settings,
+            ) {
+                if !schema.has_key("font-size") {
+                    {
+                        {
+                            let log_domain = <Option<
+                                &str,
+                            > as std::convert::From<_>>::from("GLib-GObject");
+                            {
+                                let mut w = ::glib::GStringBuilder::default();
+                                if !std::fmt::Write::write_fmt(
+                                        &mut w,
+                                        format_args!(
+                                            "property \"{0}\" of type \'{1}\' is stored in settings key \"{2}\", which does not exist",
+                                            "font-size",
+                                            object.type_(),
+                                            "font-size",
+                                        ),
+                                    )
+                                    .is_err()
+                                {
+                                    unsafe {
+                                        ::glib::ffi::g_log(
+                                            ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                            <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                ::glib::LogLevel::Critical,
+                                            ),
+                                            b"%s\0".as_ptr() as *const _,
+                                            ::glib::translate::ToGlibPtr::<
+                                                *const std::os::raw::c_char,
+                                            >::to_glib_none(&w.into_string())
+                                                .0,
+                                        );
+                                    }
+                                }
+                            };
+                        };
+                    };
+                } else if schema.key("font-size").value_type().as_str() != "i" {
+                    {
+                        {
+                            let log_domain = <Option<
+                                &str,
+                            > as std::convert::From<_>>::from("GLib-GObject");
+                            {
+                                let mut w = ::glib::GStringBuilder::default();
+                                if !std::fmt::Write::write_fmt(
+                                        &mut w,
+                                        format_args!(
+                                            "property \"{0}\" of type \'{1}\' expects settings key \"{2}\" of type \'{3}\', not \'{4}\'",
+                                            "font-size",
+                                            object.type_(),
+                                            "font-size",
+                                            "i",
+                                            schema.key("font-size").value_type().as_str(),
+                                        ),
+                                    )
+                                    .is_err()
+                                {
+                                    unsafe {
+                                        ::glib::ffi::g_log(
+                                            ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                            <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                ::glib::LogLevel::Critical,
+                                            ),
+                                            b"%s\0".as_ptr() as *const _,
+                                            ::glib::translate::ToGlibPtr::<
+                                                *const std::os::raw::c_char,
+                                            >::to_glib_none(&w.into_string())
+                                                .0,
+                                        );
+                                    }
+                                }
+                            };
+                        };
+                    };
+                }
+            }
+            let object_weak = object.downgrade();
+            gtk::gio::prelude::SettingsExt::connect_changed(
+                settings,
+                Some("font-size"),
+                move |_, _| {
+                    if let Some(object) = object_weak.upgrade() {
+                        object.notify("font-size");
+                    }
+                },
+            );
+        }
+        {
+            let settings = &self.settings;
+            if let Some(schema) = gtk::gio::prelude::SettingsExt::settings_schema(
+                settings,
+            ) {
+                if !schema.has_key("font-name") {
+                    {
+                        {
+                            let log_domain = <Option<
+                                &str,
+                            > as std::convert::From<_>>::from("GLib-GObject");
+                            {
+                                let mut w = ::glib::GStringBuilder::default();
+                                if !std::fmt::Write::write_fmt(
+                                        &mut w,
+                                        format_args!(
+                                            "property \"{0}\" of type \'{1}\' is stored in settings key \"{2}\", which does not exist",
+                                            "font-name",
+                                            object.type_(),
+                                            "font-name",
+                                        ),
+                                    )
+                                    .is_err()
+                                {
+                                    unsafe {
+                                        ::glib::ffi::g_log(
+                                            ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                            <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                ::glib::LogLevel::Critical,
+                                            ),
+                                            b"%s\0".as_ptr() as *const _,
+                                            ::glib::translate::ToGlibPtr::<
+                                                *const std::os::raw::c_char,
+                                            >::to_glib_none(&w.into_string())
+                                                .0,
+                                        );
+                                    }
+                                }
+                            };
+                        };
+                    };
+                } else if schema.key("font-name").value_type().as_str() != "s" {
+                    {
+                        {
+                            let log_domain = <Option<
+                                &str,
+                            > as std::convert::From<_>>::from("GLib-GObject");
+                            {
+                                let mut w = ::glib::GStringBuilder::default();
+                                if !std::fmt::Write::write_fmt(
+                                        &mut w,
+                                        format_args!(
+                                            "property \"{0}\" of type \'{1}\' expects settings key \"{2}\" of type \'{3}\', not \'{4}\'",
+                                            "font-name",
+                                            object.type_(),
+                                            "font-name",
+                                            "s",
+                                            schema.key("font-name").value_type().as_str(),
+                                        ),
+                                    )
+                                    .is_err()
+                                {
+                                    unsafe {
+                                        ::glib::ffi::g_log(
+                                            ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                            <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                ::glib::LogLevel::Critical,
+                                            ),
+                                            b"%s\0".as_ptr() as *const _,
+                                            ::glib::translate::ToGlibPtr::<
+                                                *const std::os::raw::c_char,
+                                            >::to_glib_none(&w.into_string())
+                                                .0,
+                                        );
+                                    }
+                                }
+                            };
+                        };
+                    };
+                }
+            }
+            let object_weak = object.downgrade();
+            gtk::gio::prelude::SettingsExt::connect_changed(
+                settings,
+                Some("font-name"),
+                move |_, _| {
+                    if let Some(object) = object_weak.upgrade() {
+                        object.notify("font-name");
+                    }
+                },
+            );
+        }
+    }
+}
+struct FontPreferences {
+    settings: gio::Settings,
+    default_size: Cell<i32>,
+}
+impl ObjectImpl for FontPreferences {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `font-size` | int | explicit_notify, readwrite |  |  |  |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecInt::builder("font-size")
+                            .flags(
+                                gtk::glib::ParamFlags::EXPLICIT_NOTIFY
+                                    | gtk::glib::ParamFlags::READWRITE,
+                            )
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                gtk::gio::prelude::SettingsExt::int(&self.settings, "font-size")
+                    .to_value()
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                let result = gtk::gio::prelude::SettingsExt::set_int(
+                    &self.settings,
+                    "font-size",
+                    value.get().unwrap(),
+                );
+                if let Err(err) = result {
+                    {
+                        {
+                            let log_domain = <Option<
+                                &str,
+                            > as std::convert::From<_>>::from("GLib-GObject");
+                            {
+                                let mut w = ::glib::GStringBuilder::default();
+                                if !std::fmt::Write::write_fmt(
+                                        &mut w,
+                                        format_args!(
+                                            "property \"{0}\" of type \'{1}\' cannot be stored in settings key \"{2}\": {3}",
+                                            pspec.name(),
+                                            object.type_(),
+                                            "font-size",
+                                            err,
+                                        ),
+                                    )
+                                    .is_err()
+                                {
+                                    unsafe {
+                                        ::glib::ffi::g_log(
+                                            ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                            <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                ::glib::LogLevel::Warning,
+                                            ),
+                                            b"%s\0".as_ptr() as *const _,
+                                            ::glib::translate::ToGlibPtr::<
+                                                *const std::os::raw::c_char,
+                                            >::to_glib_none(&w.into_string())
+                                                .0,
+                                        );
+                                    }
+                                }
+                            };
+                        };
+                    };
+                }
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+    fn constructed(&self, obj: &Self::Type) {
+        {
+            use gtk::glib::prelude::*;
+            let object: &Self::Type = obj;
+            {
+                let settings = &self.settings;
+                if let Some(schema) = gtk::gio::prelude::SettingsExt::settings_schema(
+                    settings,
+                ) {
+                    if !schema.has_key("font-size") {
+                        {
+                            {
+                                let log_domain = <Option<
+                                    &str,
+                                > as std::convert::From<_>>::from("GLib-GObject");
+                                {
+                                    let mut w = ::glib::GStringBuilder::default();
+                                    if !std::fmt::Write::write_fmt(
+                                            &mut w,
+                                            format_args!(
+                                                "property \"{0}\" of type \'{1}\' is stored in settings key \"{2}\", which does not exist",
+                                                "font-size",
+                                                object.type_(),
+                                                "font-size",
+                                            ),
+                                        )
+                                        .is_err()
+                                    {
+                                        unsafe {
+                                            ::glib::ffi::g_log(
+                                                ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                                <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                    ::glib::LogLevel::Critical,
+                                                ),
+                                                b"%s\0".as_ptr() as *const _,
+                                                ::glib::translate::ToGlibPtr::<
+                                                    *const std::os::raw::c_char,
+                                                >::to_glib_none(&w.into_string())
+                                                    .0,
+                                            );
+                                        }
+                                    }
+                                };
+                            };
+                        };
+                    } else if schema.key("font-size").value_type().as_str() != "i" {
+                        {
+                            {
+                                let log_domain = <Option<
+                                    &str,
+                                > as std::convert::From<_>>::from("GLib-GObject");
+                                {
+                                    let mut w = ::glib::GStringBuilder::default();
+                                    if !std::fmt::Write::write_fmt(
+                                            &mut w,
+                                            format_args!(
+                                                "property \"{0}\" of type \'{1}\' expects settings key \"{2}\" of type \'{3}\', not \'{4}\'",
+                                                "font-size",
+                                                object.type_(),
+                                                "font-size",
+                                                "i",
+                                                schema.key("font-size").value_type().as_str(),
+                                            ),
+                                        )
+                                        .is_err()
+                                    {
+                                        unsafe {
+                                            ::glib::ffi::g_log(
+                                                ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                                <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                    ::glib::LogLevel::Critical,
+                                                ),
+                                                b"%s\0".as_ptr() as *const _,
+                                                ::glib::translate::ToGlibPtr::<
+                                                    *const std::os::raw::c_char,
+                                                >::to_glib_none(&w.into_string())
+                                                    .0,
+                                            );
+                                        }
+                                    }
+                                };
+                            };
+                        };
+                    }
+                }
+                let object_weak = object.downgrade();
+                gtk::gio::prelude::SettingsExt::connect_changed(
+                    settings,
+                    Some("font-size"),
+                    move |_, _| {
+                        if let Some(object) = object_weak.upgrade() {
+                            object.notify("font-size");
+                        }
+                    },
+                );
+            }
+        }
+        self.parent_constructed(obj);
+        self.default_size.set(12);
+    }
+}
+struct BoundPreferences {
+    settings: gio::Settings,
+}
+impl ObjectImpl for BoundPreferences {
+    /**Properties:
+
+| Name | Type | Flags | Range | Default | Description |
+|------|------|-------|-------|---------|-------------|*/
+    ///| `font-size` | int | explicit_notify, readwrite |  |  |  |
+    fn properties() -> &'static [gtk::glib::ParamSpec] {
+        use gtk::glib::*;
+        static PROPERTIES: std::sync::OnceLock<Vec<ParamSpec>> = std::sync::OnceLock::new();
+        PROPERTIES
+            .get_or_init(|| {
+                <[_]>::into_vec(
+                    #[rustc_box]
+                    ::alloc::boxed::Box::new([
+                        ParamSpecInt::builder("font-size")
+                            .flags(
+                                gtk::glib::ParamFlags::EXPLICIT_NOTIFY
+                                    | gtk::glib::ParamFlags::READWRITE,
+                            )
+                            .build(),
+                    ]),
+                )
+            })
+            .as_ref()
+    }
+    fn property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        pspec: &gtk::glib::ParamSpec,
+    ) -> gtk::glib::Value {
+        #[allow(non_local_definitions)]
+        impl BoundPreferences {
+            fn bind_settings(
+                &self,
+                object: &<BoundPreferences as gtk::glib::subclass::types::ObjectSubclass>::Type,
+            ) {
+                use gtk::glib::prelude::*;
+                {
+                    let settings = &self.settings;
+                    if let Some(schema) = gtk::gio::prelude::SettingsExt::settings_schema(
+                        settings,
+                    ) {
+                        if !schema.has_key("font-size") {
+                            {
+                                {
+                                    let log_domain = <Option<
+                                        &str,
+                                    > as std::convert::From<_>>::from("GLib-GObject");
+                                    {
+                                        let mut w = ::glib::GStringBuilder::default();
+                                        if !std::fmt::Write::write_fmt(
+                                                &mut w,
+                                                format_args!(
+                                                    "property \"{0}\" of type \'{1}\' is stored in settings key \"{2}\", which does not exist",
+                                                    "font-size",
+                                                    object.type_(),
+                                                    "font-size",
+                                                ),
+                                            )
+                                            .is_err()
+                                        {
+                                            unsafe {
+                                                ::glib::ffi::g_log(
+                                                    ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                                    <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                        ::glib::LogLevel::Critical,
+                                                    ),
+                                                    b"%s\0".as_ptr() as *const _,
+                                                    ::glib::translate::ToGlibPtr::<
+                                                        *const std::os::raw::c_char,
+                                                    >::to_glib_none(&w.into_string())
+                                                        .0,
+                                                );
+                                            }
+                                        }
+                                    };
+                                };
+                            };
+                        } else if schema.key("font-size").value_type().as_str() != "i" {
+                            {
+                                {
+                                    let log_domain = <Option<
+                                        &str,
+                                    > as std::convert::From<_>>::from("GLib-GObject");
+                                    {
+                                        let mut w = ::glib::GStringBuilder::default();
+                                        if !std::fmt::Write::write_fmt(
+                                                &mut w,
+                                                format_args!(
+                                                    "property \"{0}\" of type \'{1}\' expects settings key \"{2}\" of type \'{3}\', not \'{4}\'",
+                                                    "font-size",
+                                                    object.type_(),
+                                                    "font-size",
+                                                    "i",
+                                                    schema.key("font-size").value_type().as_str(),
+                                                ),
+                                            )
+                                            .is_err()
+                                        {
+                                            unsafe {
+                                                ::glib::ffi::g_log(
+                                                    ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                                    <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                        ::glib::LogLevel::Critical,
+                                                    ),
+                                                    b"%s\0".as_ptr() as *const _,
+                                                    ::glib::translate::ToGlibPtr::<
+                                                        *const std::os::raw::c_char,
+                                                    >::to_glib_none(&w.into_string())
+                                                        .0,
+                                                );
+                                            }
+                                        }
+                                    };
+                                };
+                            };
+                        }
+                    }
+                    let object_weak = object.downgrade();
+                    gtk::gio::prelude::SettingsExt::connect_changed(
+                        settings,
+                        Some("font-size"),
+                        move |_, _| {
+                            if let Some(object) = object_weak.upgrade() {
+                                object.notify("font-size");
+                            }
+                        },
+                    );
+                }
+            }
+        }
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                gtk::gio::prelude::SettingsExt::int(&self.settings, "font-size")
+                    .to_value()
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+                gtk::glib::Value::from_type(pspec.value_type())
+            }
+        }
+    }
+    fn set_property(
+        &self,
+        object: &Self::Type,
+        id: usize,
+        value: &gtk::glib::Value,
+        pspec: &gtk::glib::ParamSpec,
+    ) {
+        use gtk::glib::prelude::*;
+        match id {
+            1usize => {
+                let result = gtk::gio::prelude::SettingsExt::set_int(
+                    &self.settings,
+                    "font-size",
+                    value.get().unwrap(),
+                );
+                if let Err(err) = result {
+                    {
+                        {
+                            let log_domain = <Option<
+                                &str,
+                            > as std::convert::From<_>>::from("GLib-GObject");
+                            {
+                                let mut w = ::glib::GStringBuilder::default();
+                                if !std::fmt::Write::write_fmt(
+                                        &mut w,
+                                        format_args!(
+                                            "property \"{0}\" of type \'{1}\' cannot be stored in settings key \"{2}\": {3}",
+                                            pspec.name(),
+                                            object.type_(),
+                                            "font-size",
+                                            err,
+                                        ),
+                                    )
+                                    .is_err()
+                                {
+                                    unsafe {
+                                        ::glib::ffi::g_log(
+                                            ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                            <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                                ::glib::LogLevel::Warning,
+                                            ),
+                                            b"%s\0".as_ptr() as *const _,
+                                            ::glib::translate::ToGlibPtr::<
+                                                *const std::os::raw::c_char,
+                                            >::to_glib_none(&w.into_string())
+                                                .0,
+                                        );
+                                    }
+                                }
+                            };
+                        };
+                    };
+                }
+            }
+            _ => {
+                {
+                    {
+                        let log_domain = <Option<
+                            &str,
+                        > as std::convert::From<_>>::from("GLib-GObject");
+                        {
+                            let mut w = ::glib::GStringBuilder::default();
+                            if !std::fmt::Write::write_fmt(
+                                    &mut w,
+                                    format_args!(
+                                        "invalid property id {0} for \"{1}\" of type \'{2}\' in \'{3}\'",
+                                        id,
+                                        pspec.name(),
+                                        pspec.value_type(),
+                                        object.type_(),
+                                    ),
+                                )
+                                .is_err()
+                            {
+                                unsafe {
+                                    ::glib::ffi::g_log(
+                                        ::glib::translate::ToGlibPtr::to_glib_none(&log_domain).0,
+                                        <::glib::LogLevel as ::glib::translate::IntoGlib>::into_glib(
+                                            ::glib::LogLevel::Critical,
+                                        ),
+                                        b"%s\0".as_ptr() as *const _,
+                                        ::glib::translate::ToGlibPtr::<
+                                            *const std::os::raw::c_char,
+                                        >::to_glib_none(&w.into_string())
+                                            .0,
+                                    );
+                                }
+                            }
+                        };
+                    };
+                };
+            }
+        }
+    }
+    fn constructed(&self, obj: &Self::Type) {
+        self.parent_constructed(obj);
+        self.bind_settings(obj);
+    }
+}
@@ -0,0 +1,49 @@
+use gtk_properties_macro::properties;
+
+struct Preferences {
+    settings: gio::Settings,
+}
+
+impl ObjectImpl for Preferences {
+    properties! {
+        #[int]
+        "font-size" => settings(self.settings, "font-size"),
+        #[string(readable)]
+        "font-name" => settings(self.settings, "font-name"),
+    }
+}
+
+struct FontPreferences {
+    settings: gio::Settings,
+    default_size: Cell<i32>,
+}
+
+impl ObjectImpl for FontPreferences {
+    properties! {
+        #[int]
+        "font-size" => settings(self.settings, "font-size"),
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+            self.default_size.set(12);
+        }
+    }
+}
+
+struct BoundPreferences {
+    settings: gio::Settings,
+}
+
+impl ObjectImpl for BoundPreferences {
+    properties! {
+        #![bind_settings = BoundPreferences]
+
+        #[int]
+        "font-size" => settings(self.settings, "font-size"),
+    }
+
+    fn constructed(&self, obj: &Self::Type) {
+        self.parent_constructed(obj);
+        self.bind_settings(obj);
+    }
+}
@@ -0,0 +1,15 @@
+use gtk_properties_macro::properties;
+
+struct MyObject {
+    count: Cell<i32>,
+    name: RefCell<String>,
+}
+
+impl ObjectImpl for MyObject {
+    properties! {
+        #[int]
+        "count" => cell(count),
+        #[string(readable)]
+        "name" => refcell(name),
+    }
+}
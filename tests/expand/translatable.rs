@@ -0,0 +1,17 @@
+use gtk_properties_macro::properties;
+
+struct MyObject {
+    zoom: Cell<f64>,
+    title: RefCell<String>,
+}
+
+impl ObjectImpl for MyObject {
+    properties! {
+        /// How far the view is zoomed in
+        #[double(translatable, nick = "Zoom level")]
+        "zoom-level" => cell(zoom),
+        /// Not translated
+        #[string(nick = "Title")]
+        "title" => refcell(title),
+    }
+}
@@ -0,0 +1,26 @@
+use gtk_properties_macro::properties;
+
+struct MyObject {
+    name: RefCell<String>,
+    email: RefCell<String>,
+}
+
+impl ObjectImpl for MyObject {
+    properties! {
+        #[string]
+        "name" => {
+            get { self.name.borrow().to_value() }
+            validate = not_empty
+            set { self.name.replace(value.get().unwrap()); }
+        }
+        #[string]
+        "email" => {
+            get { self.email.borrow().to_value() }
+            validate {
+                let email: String = value.get().unwrap();
+                if email.contains('@') { Ok(()) } else { Err("not an email address") }
+            }
+            set { self.email.replace(value.get().unwrap()); }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use gtk_properties_macro::properties;
+
+struct MyObject {
+    owner: glib::WeakRef<gtk::Widget>,
+    parent: glib::WeakRef<glib::Object>,
+}
+
+impl ObjectImpl for MyObject {
+    properties! {
+        #[object(gtk::Widget)]
+        "owner" => weak(owner),
+        #[object(glib::Object, nullable)]
+        "parent" => weak(parent),
+    }
+}
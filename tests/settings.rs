@@ -0,0 +1,157 @@
+// Properties stored in settings, against the schema in tests/data, compiled at runtime
+// and used with the memory backend.
+use gtk::gio;
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk_properties_macro::properties;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+fn settings() -> gio::Settings {
+    let dir = std::env::temp_dir().join(format!("gtk-properties-macro-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::copy(
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/data/org.example.Preferences.gschema.xml"
+        ),
+        dir.join("org.example.Preferences.gschema.xml"),
+    )
+    .unwrap();
+    let status = std::process::Command::new("glib-compile-schemas")
+        .arg(&dir)
+        .status()
+        .expect("glib-compile-schemas is needed to compile the test schema");
+    assert!(status.success());
+    let source = gio::SettingsSchemaSource::from_directory(&dir, None, false).unwrap();
+    let schema = source.lookup("org.example.Preferences", false).unwrap();
+    gio::Settings::new_full(&schema, Some(&gio::memory_settings_backend_new()), None)
+}
+
+mod imp {
+    use super::*;
+
+    pub struct Preferences {
+        pub settings: gio::Settings,
+        pub constructed: Cell<bool>,
+    }
+
+    impl Default for Preferences {
+        fn default() -> Self {
+            Preferences {
+                settings: settings(),
+                constructed: Cell::new(false),
+            }
+        }
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Preferences {
+        const NAME: &'static str = "SettingsPreferences";
+        type Type = super::Preferences;
+        type ParentType = glib::Object;
+    }
+
+    impl ObjectImpl for Preferences {
+        properties! {
+            #![schema = "tests/data/org.example.Preferences.gschema.xml"]
+
+            #[int]
+            "font-size" => settings(self.settings, "font-size"),
+            #[string]
+            "font-name" => settings(self.settings, "font-name"),
+
+            fn constructed(&self, obj: &Self::Type) {
+                self.parent_constructed(obj);
+                self.constructed.set(true);
+            }
+        }
+    }
+}
+
+mod bound {
+    use super::*;
+
+    pub struct BoundPreferences {
+        pub settings: gio::Settings,
+    }
+
+    impl Default for BoundPreferences {
+        fn default() -> Self {
+            BoundPreferences {
+                settings: settings(),
+            }
+        }
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for BoundPreferences {
+        const NAME: &'static str = "SettingsBoundPreferences";
+        type Type = super::BoundPreferences;
+        type ParentType = glib::Object;
+    }
+
+    // `constructed` is implemented outside of `properties!`, and calls `bind_settings`
+    impl ObjectImpl for BoundPreferences {
+        properties! {
+            #![bind_settings = BoundPreferences]
+
+            #[int]
+            "font-size" => settings(self.settings, "font-size"),
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+            self.bind_settings(obj);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct Preferences(ObjectSubclass<imp::Preferences>);
+}
+
+glib::wrapper! {
+    pub struct BoundPreferences(ObjectSubclass<bound::BoundPreferences>);
+}
+
+#[test]
+fn reads_and_writes_keys() {
+    let preferences: Preferences = glib::Object::new(&[]).unwrap();
+    let imp = imp::Preferences::from_instance(&preferences);
+    assert!(imp.constructed.get());
+    assert_eq!(preferences.property::<i32>("font-size"), 12);
+    preferences.set_property("font-size", 14);
+    assert_eq!(imp.settings.int("font-size"), 14);
+    preferences.set_property("font-name", "Serif");
+    assert_eq!(imp.settings.string("font-name"), "Serif");
+}
+
+#[test]
+fn notifies_when_keys_change() {
+    let preferences: Preferences = glib::Object::new(&[]).unwrap();
+    let imp = imp::Preferences::from_instance(&preferences);
+    let notified = Rc::new(RefCell::new(vec![]));
+    preferences.connect_notify_local(None, {
+        let notified = notified.clone();
+        move |_, pspec| notified.borrow_mut().push(pspec.name().to_string())
+    });
+    imp.settings.set_int("font-size", 16).unwrap();
+    assert_eq!(preferences.property::<i32>("font-size"), 16);
+    assert_eq!(*notified.borrow(), ["font-size"]);
+}
+
+#[test]
+fn binds_settings_from_own_constructed() {
+    let preferences: BoundPreferences = glib::Object::new(&[]).unwrap();
+    let imp = bound::BoundPreferences::from_instance(&preferences);
+    let notified = Rc::new(RefCell::new(vec![]));
+    preferences.connect_notify_local(None, {
+        let notified = notified.clone();
+        move |_, pspec| notified.borrow_mut().push(pspec.name().to_string())
+    });
+    imp.settings.set_int("font-size", 18).unwrap();
+    assert_eq!(preferences.property::<i32>("font-size"), 18);
+    assert_eq!(*notified.borrow(), ["font-size"]);
+}